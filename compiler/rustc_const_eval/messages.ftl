@@ -137,6 +137,7 @@ const_eval_incompatible_types =
 const_eval_interior_mutable_data_refer =
     {const_eval_const_context}s cannot refer to interior mutable data
     .label = this borrow of an interior mutable value may end up in the final value
+    .borrowed_ty_note = the borrowed value has type `{$borrowed_ty}`, which has interior mutability through an `UnsafeCell`
     .help = to fix this, the value can be extracted to a separate `static` item and then referenced
     .teach_note =
         A constant containing interior mutable data behind a reference can allow you to modify that data.
@@ -236,6 +237,15 @@ const_eval_non_const_fmt_macro_call =
 const_eval_non_const_fn_call =
     cannot call non-const fn `{$def_path_str}` in {const_eval_const_context}s
 
+const_eval_non_const_fn_def =
+    function defined here, but it is not `const`
+
+const_eval_non_const_fn_extern_help =
+    the crate `{$crate_name}` would need to declare this function as a `const fn`
+
+const_eval_non_const_fn_local_help =
+    consider making this function a `const fn`
+
 const_eval_non_const_impl =
     impl defined here, but it is not `const`
 
@@ -429,7 +429,8 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
                 );
 
                 if borrowed_place_has_mut_interior && self.place_may_escape(place) {
-                    self.check_op(ops::EscapingCellBorrow);
+                    let borrowed_ty = place.ty(self.body, self.tcx).ty;
+                    self.check_op(ops::EscapingCellBorrow { borrowed_ty });
                 }
             }
 
@@ -5,6 +5,7 @@
 use rustc_errors::codes::*;
 use rustc_errors::Diag;
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_hir::def_id::DefId;
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_infer::traits::{ImplSource, Obligation, ObligationCause};
@@ -266,11 +267,30 @@ macro_rules! error {
             _ if tcx.opt_parent(callee) == tcx.get_diagnostic_item(sym::ArgumentMethods) => {
                 ccx.dcx().create_err(errors::NonConstFmtMacroCall { span, kind: ccx.const_kind() })
             }
-            _ => ccx.dcx().create_err(errors::NonConstFnCall {
-                span,
-                def_path_str: ccx.tcx.def_path_str_with_args(callee, args),
-                kind: ccx.const_kind(),
-            }),
+            _ => {
+                let mut err = ccx.dcx().create_err(errors::NonConstFnCall {
+                    span,
+                    def_path_str: ccx.tcx.def_path_str_with_args(callee, args),
+                    kind: ccx.const_kind(),
+                });
+
+                // Point at plain functions that could be made `const`. Calls that come from a
+                // macro expansion are not something the user wrote, so don't bother them there.
+                let is_plain_fn = matches!(tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn)
+                    && !tcx.is_foreign_item(callee)
+                    && tcx.trait_of_item(callee).is_none()
+                    && tcx.impl_of_method(callee).and_then(|i| tcx.trait_id_of_impl(i)).is_none();
+                if is_plain_fn && !span.from_expansion() {
+                    err.subdiagnostic(errors::NonConstFnDefNote { span: tcx.def_span(callee) });
+                    if callee.is_local() {
+                        err.help(fluent_generated::const_eval_non_const_fn_local_help);
+                    } else {
+                        let crate_name = tcx.crate_name(callee.krate);
+                        err.subdiagnostic(errors::NonConstExternFnHelp { crate_name });
+                    }
+                }
+                err
+            }
         };
 
         err.note(format!(
@@ -395,8 +415,11 @@ fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> Diag<'tcx> {
 /// A borrow of a type that contains an `UnsafeCell` somewhere. The borrow might escape to
 /// the final value of the constant, and thus we cannot allow this (for now). We may allow
 /// it in the future for static items.
-pub(crate) struct EscapingCellBorrow;
-impl<'tcx> NonConstOp<'tcx> for EscapingCellBorrow {
+pub(crate) struct EscapingCellBorrow<'tcx> {
+    /// The type of the borrowed place.
+    pub borrowed_ty: Ty<'tcx>,
+}
+impl<'tcx> NonConstOp<'tcx> for EscapingCellBorrow<'tcx> {
     fn importance(&self) -> DiagImportance {
         // Most likely the code will try to do mutation with these borrows, which
         // triggers its own errors. Only show this one if that does not happen.
@@ -405,6 +428,7 @@ fn importance(&self) -> DiagImportance {
     fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> Diag<'tcx> {
         ccx.dcx().create_err(errors::InteriorMutableDataRefer {
             span,
+            borrowed_ty: self.borrowed_ty,
             opt_help: matches!(ccx.const_kind(), hir::ConstContext::Static(_)),
             kind: ccx.const_kind(),
             teach: ccx.tcx.sess.teach(E0492),
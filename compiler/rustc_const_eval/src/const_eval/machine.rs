@@ -580,6 +580,7 @@ fn assert_panic(
                     found: eval_to_int(found)?,
                 }
             }
+            NullPointerDereference => NullPointerDereference,
         };
         Err(ConstEvalErrKind::AssertFailure(err).into())
     }
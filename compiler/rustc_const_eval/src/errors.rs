@@ -182,10 +182,12 @@ pub(crate) struct UnallowedInlineAsm {
 
 #[derive(Diagnostic)]
 #[diag(const_eval_interior_mutable_data_refer, code = E0492)]
-pub(crate) struct InteriorMutableDataRefer {
+#[note(const_eval_borrowed_ty_note)]
+pub(crate) struct InteriorMutableDataRefer<'tcx> {
     #[primary_span]
     #[label]
     pub span: Span,
+    pub borrowed_ty: Ty<'tcx>,
     #[help]
     pub opt_help: bool,
     pub kind: ConstContext,
@@ -218,6 +220,19 @@ pub(crate) struct NonConstImplNote {
     pub span: Span,
 }
 
+#[derive(Subdiagnostic)]
+#[note(const_eval_non_const_fn_def)]
+pub(crate) struct NonConstFnDefNote {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Subdiagnostic)]
+#[help(const_eval_non_const_fn_extern_help)]
+pub(crate) struct NonConstExternFnHelp {
+    pub crate_name: Symbol,
+}
+
 #[derive(Subdiagnostic, Clone)]
 #[note(const_eval_frame_note)]
 pub struct FrameNote {
@@ -140,6 +140,15 @@ pub fn codegen_and_build_linker(
             tcx.sess.code_stats.print_vtable_sizes(crate_name);
         }
 
+        // Codegen has requested the optimized MIR of every item it needs by now.
+        if tcx.sess.opts.unstable_opts.time_passes {
+            let format = tcx.sess.opts.unstable_opts.time_passes_format;
+            tcx.sess.code_stats.print_mir_pass_times(format);
+        }
+        if tcx.sess.opts.unstable_opts.mir_stats.is_some() {
+            tcx.sess.code_stats.print_mir_stats();
+        }
+
         Ok(Linker {
             dep_graph: tcx.dep_graph.clone(),
             output_filenames: tcx.output_filenames(()).clone(),
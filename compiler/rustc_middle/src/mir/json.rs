@@ -0,0 +1,129 @@
+//! A machine-readable representation of MIR bodies, emitted by `-Z dump-mir-json`.
+//!
+//! The schema is documented in the unstable book under `dump-mir-json`. It is meant for tools
+//! that want to inspect the structure of a body without parsing the textual MIR dump, so it
+//! describes the control-flow graph, locals and spans, while statements, terminators and types
+//! are given in the same textual form as in the `.mir` files. Changes to the schema should be
+//! reflected in the `version` field.
+
+use std::fmt::Display;
+use std::io;
+
+use rustc_middle::mir::*;
+use rustc_span::Span;
+use serde::Serialize;
+
+/// Bumped whenever the layout of the JSON document changes.
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonBody {
+    version: u32,
+    def_path: String,
+    promoted: Option<usize>,
+    pass_name: String,
+    disambiguator: String,
+    phase: &'static str,
+    arg_count: usize,
+    span: String,
+    locals: Vec<JsonLocal>,
+    basic_blocks: Vec<JsonBasicBlock>,
+}
+
+#[derive(Serialize)]
+struct JsonLocal {
+    local: String,
+    ty: String,
+    mutable: bool,
+    span: String,
+}
+
+#[derive(Serialize)]
+struct JsonBasicBlock {
+    block: String,
+    is_cleanup: bool,
+    statements: Vec<JsonStatement>,
+    terminator: JsonTerminator,
+}
+
+#[derive(Serialize)]
+struct JsonStatement {
+    kind: &'static str,
+    text: String,
+    span: String,
+}
+
+#[derive(Serialize)]
+struct JsonTerminator {
+    kind: &'static str,
+    text: String,
+    successors: Vec<String>,
+    span: String,
+}
+
+/// Write a JSON document describing `body` to `w`.
+pub fn write_mir_fn_json<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    pass_name: &str,
+    disambiguator: &dyn Display,
+    w: &mut dyn io::Write,
+) -> io::Result<()> {
+    let span = |span: Span| tcx.sess.source_map().span_to_embeddable_string(span);
+
+    let locals = body
+        .local_decls
+        .iter_enumerated()
+        .map(|(local, decl)| JsonLocal {
+            local: format!("{local:?}"),
+            ty: decl.ty.to_string(),
+            mutable: decl.mutability.is_mut(),
+            span: span(decl.source_info.span),
+        })
+        .collect();
+
+    let basic_blocks = body
+        .basic_blocks
+        .iter_enumerated()
+        .map(|(block, data)| {
+            let statements = data
+                .statements
+                .iter()
+                .map(|statement| JsonStatement {
+                    kind: statement.kind.name(),
+                    text: format!("{statement:?}"),
+                    span: span(statement.source_info.span),
+                })
+                .collect();
+            let terminator = data.terminator();
+            JsonBasicBlock {
+                block: format!("{block:?}"),
+                is_cleanup: data.is_cleanup,
+                statements,
+                terminator: JsonTerminator {
+                    kind: terminator.kind.name(),
+                    text: format!("{:?}", terminator.kind),
+                    successors: terminator.successors().map(|bb| format!("{bb:?}")).collect(),
+                    span: span(terminator.source_info.span),
+                },
+            }
+        })
+        .collect();
+
+    let json = JsonBody {
+        version: SCHEMA_VERSION,
+        def_path: ty::print::with_forced_impl_filename_line!(
+            tcx.def_path_str(body.source.def_id())
+        ),
+        promoted: body.source.promoted.map(|promoted| promoted.as_usize()),
+        pass_name: pass_name.to_string(),
+        disambiguator: disambiguator.to_string(),
+        phase: body.phase.name(),
+        arg_count: body.arg_count,
+        span: span(body.span),
+        locals,
+        basic_blocks,
+    };
+    serde_json::to_writer_pretty(&mut *w, &json)?;
+    writeln!(w)
+}
@@ -15,6 +15,7 @@
 use tracing::trace;
 
 use super::graphviz::write_mir_fn_graphviz;
+use super::json::write_mir_fn_json;
 use crate::mir::interpret::ConstAllocation;
 
 const INDENT: &str = "    ";
@@ -187,6 +188,13 @@ fn dump_matched_mir_node<'tcx, F>(
             write_mir_fn_graphviz(tcx, body, false, &mut file)?;
         };
     }
+
+    if tcx.sess.opts.unstable_opts.dump_mir_json {
+        let _: io::Result<()> = try {
+            let mut file = create_dump_file(tcx, "json", pass_num, pass_name, disambiguator, body)?;
+            write_mir_fn_json(tcx, body, pass_name, disambiguator, &mut file)?;
+        };
+    }
 }
 
 /// Returns the path to the filename where we should dump a given MIR.
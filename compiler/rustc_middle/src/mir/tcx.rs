@@ -366,4 +366,36 @@ pub fn wrapping_to_overflowing(self) -> Option<BinOp> {
             _ => return None,
         })
     }
+
+    /// Returns whether swapping the operands of this operation gives the same result.
+    pub fn is_commutative(self) -> bool {
+        match self {
+            BinOp::Add
+            | BinOp::AddUnchecked
+            | BinOp::AddWithOverflow
+            | BinOp::Mul
+            | BinOp::MulUnchecked
+            | BinOp::MulWithOverflow
+            | BinOp::BitXor
+            | BinOp::BitAnd
+            | BinOp::BitOr
+            | BinOp::Eq
+            | BinOp::Ne => true,
+            BinOp::Sub
+            | BinOp::SubUnchecked
+            | BinOp::SubWithOverflow
+            | BinOp::Div
+            | BinOp::Rem
+            | BinOp::Shl
+            | BinOp::ShlUnchecked
+            | BinOp::Shr
+            | BinOp::ShrUnchecked
+            | BinOp::Lt
+            | BinOp::Le
+            | BinOp::Gt
+            | BinOp::Ge
+            | BinOp::Cmp
+            | BinOp::Offset => false,
+        }
+    }
 }
@@ -157,10 +157,11 @@ pub fn is_optional_overflow_check(&self) -> bool {
 
     /// Get the lang item that is invoked to print a static message when this assert fires.
     ///
-    /// The caller is expected to handle `BoundsCheck` and `MisalignedPointerDereference` by
-    /// invoking the appropriate lang item (panic_bounds_check/panic_misaligned_pointer_dereference)
-    /// instead of printing a static message. Those have dynamic arguments that aren't present for
-    /// the rest of the messages here.
+    /// The caller is expected to handle `BoundsCheck`, `MisalignedPointerDereference` and
+    /// `NullPointerDereference` by invoking the appropriate lang item (panic_bounds_check,
+    /// panic_misaligned_pointer_dereference or panic_null_pointer_dereference) instead of printing
+    /// a static message. Those take the caller location as an argument, and the first two also have
+    /// dynamic arguments that aren't present for the rest of the messages here.
     pub fn panic_function(&self) -> LangItem {
         use AssertKind::*;
         match self {
@@ -196,7 +197,7 @@ pub fn panic_function(&self) -> LangItem {
                 LangItem::PanicGenFnNonePanic
             }
 
-            BoundsCheck { .. } | MisalignedPointerDereference { .. } => {
+            BoundsCheck { .. } | MisalignedPointerDereference { .. } | NullPointerDereference => {
                 bug!("Unexpected AssertKind")
             }
         }
@@ -260,6 +261,7 @@ pub fn fmt_assert_args<W: fmt::Write>(&self, f: &mut W) -> fmt::Result
                     "\"misaligned pointer dereference: address must be a multiple of {{}} but is {{}}\", {required:?}, {found:?}"
                 )
             }
+            NullPointerDereference => write!(f, "\"null pointer dereference occurred\""),
             ResumedAfterReturn(CoroutineKind::Coroutine(_)) => {
                 write!(f, "\"coroutine resumed after completion\"")
             }
@@ -332,6 +334,7 @@ pub fn diagnostic_message(&self) -> DiagMessage {
             }
 
             MisalignedPointerDereference { .. } => middle_assert_misaligned_ptr_deref,
+            NullPointerDereference => middle_assert_null_ptr_deref,
         }
     }
 
@@ -363,7 +366,7 @@ macro_rules! add {
                 add!("left", format!("{left:#?}"));
                 add!("right", format!("{right:#?}"));
             }
-            ResumedAfterReturn(_) | ResumedAfterPanic(_) => {}
+            ResumedAfterReturn(_) | ResumedAfterPanic(_) | NullPointerDereference => {}
             MisalignedPointerDereference { required, found } => {
                 add!("required", format!("{required:#?}"));
                 add!("found", format!("{found:#?}"));
@@ -2274,6 +2274,17 @@
         desc { "whether the item should be made inlinable across crates" }
         separate_provide_extern
     }
+
+    /// Estimates the size of the code generated for a function, in simple instructions, from its
+    /// optimized MIR. Calls, drops and landing pads are weighted as in the MIR inliner, but the
+    /// inliner's bonuses are left out, as they do not make the code smaller. This is a single walk
+    /// over the MIR, so it is much cheaper than codegen, but it does not account for the inlining
+    /// and monomorphization done later.
+    ///
+    /// `#[rustc_dump_estimated_size]` reports the estimate for a function as an error, for tests.
+    query mir_estimated_size(key: LocalDefId) -> usize {
+        desc { |tcx| "estimating the size of `{}`", tcx.def_path_str(key) }
+    }
 }
 
 rustc_query_append! { define_callbacks! }
@@ -17,12 +17,21 @@ mir_transform_ffi_unwind_call = call to {$foreign ->
 mir_transform_fn_item_ref = taking a reference to a function item does not give a function pointer
     .suggestion = cast `{$ident}` to obtain a function pointer
 
+mir_transform_mir_pass_panicked =
+    the compiler panicked while {$checking ->
+        [true] checking the MIR of `{$body}` after pass `{$pass}`
+        *[false] running MIR pass `{$pass}` on `{$body}`
+    }
+
 mir_transform_must_not_suspend = {$pre}`{$def_path}`{$post} held across a suspend point, but should not be
     .label = the value is held across this suspend point
     .note = {$reason}
     .help = consider using a block (`{"{ ... }"}`) to shrink the value's scope, ending before the suspend point
 mir_transform_operation_will_panic = this operation will panic at runtime
 
+mir_transform_slow_mir_pass = MIR pass `{$pass}` ran for {$elapsed}s on this body
+    .note = the limit set by `-Zmir-pass-timeout` is {$timeout}s
+
 mir_transform_unaligned_packed_ref = reference to packed field is unaligned
     .note = packed structs are only aligned by one byte, and many modern architectures penalize unaligned field accesses
     .note_ub = creating a misaligned reference is undefined behavior (even if that reference is never dereferenced)
@@ -52,7 +52,11 @@ fn run_pass(&self, _tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                         UnwindAction::Cleanup(_) | UnwindAction::Terminate(_)
                     ) || self == &AllCallEdges) =>
                 {
-                    // It's a critical edge, break it
+                    // It's a critical edge, break it. The guard is only entered once the call
+                    // has returned, so give it the position right after the call, to tell the
+                    // two apart in the line tables.
+                    let source_info =
+                        SourceInfo { span: source_info.span.shrink_to_hi(), ..source_info };
                     let call_guard = BasicBlockData {
                         statements: vec![],
                         is_cleanup: block.is_cleanup,
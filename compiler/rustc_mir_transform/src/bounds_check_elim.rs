@@ -0,0 +1,196 @@
+//! Removes bounds checks, and other `assert` terminators, whose condition is already known to hold.
+//!
+//! An `assert(_c, expected) -> target` is replaced by `goto -> target` when it is dominated by a
+//! block that can only be entered when `_c` has the `expected` value, that is:
+//! - a target of a `switchInt` on a `bool` equal to `_c`, when that edge is its only predecessor;
+//! - the success target of an earlier `assert` on a `bool` equal to `_c`, when that edge is its
+//!   only predecessor.
+//!
+//! This removes the checks of `s[i]` inside `if i < s.len() { .. }`, as well as the second check
+//! of `s[i] + s[i]`.
+//!
+//! Conditions are compared structurally: two SSA locals are equal if they are computed by the same
+//! operation on equal operands, where operands are constants, lengths of places behind SSA locals,
+//! or SSA locals themselves. This is sound even inside loops. Every SSA local involved is assigned
+//! before the block that establishes the fact, and the assignment of each local dominates its
+//! uses. So after the last execution of those assignments, an execution must go through that
+//! block again before reaching the `assert`, and the values cannot have changed in between.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, ParamEnv, ScalarInt, TyCtxt};
+use tracing::{debug, instrument};
+
+use crate::ssa::SsaLocals;
+
+pub(super) struct BoundsCheckElimination;
+
+impl<'tcx> crate::MirPass<'tcx> for BoundsCheckElimination {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+        let ssa = SsaLocals::new(tcx, body, param_env);
+        let values = Values {
+            tcx,
+            param_env,
+            body,
+            ssa: &ssa,
+            assignments: ssa.assignments(body).map(|(local, rvalue, _)| (local, rvalue)).collect(),
+        };
+
+        let facts = collect_facts(tcx, body, &values);
+        if facts.is_empty() {
+            return;
+        }
+
+        let dominators = body.basic_blocks.dominators();
+        let mut removed = Vec::new();
+        for (bb, data) in body.basic_blocks.iter_enumerated() {
+            if data.is_cleanup || !dominators.is_reachable(bb) {
+                continue;
+            }
+            let TerminatorKind::Assert { ref cond, expected, target, .. } = data.terminator().kind
+            else {
+                continue;
+            };
+            let Some(cond) = values.operand(cond, MAX_DEPTH) else { continue };
+            if facts.iter().any(|fact| {
+                fact.value == expected && fact.cond == cond && dominators.dominates(fact.block, bb)
+            }) {
+                removed.push((bb, target));
+            }
+        }
+
+        for (bb, target) in removed {
+            debug!(?bb, "removing assert");
+            body.basic_blocks_mut()[bb].terminator_mut().kind = TerminatorKind::Goto { target };
+        }
+    }
+}
+
+/// How many assignments we look through when comparing two conditions.
+const MAX_DEPTH: usize = 8;
+
+/// The symbolic value of an SSA local.
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Value<'tcx> {
+    Int(ScalarInt),
+    /// A local whose value we cannot describe further, like an argument or the return value of a
+    /// call.
+    Opaque(Local),
+    /// The length of an unsized place behind an SSA local.
+    Len(Place<'tcx>),
+    Unary(UnOp, Box<Value<'tcx>>),
+    Binary(BinOp, Box<Value<'tcx>>, Box<Value<'tcx>>),
+}
+
+/// A `bool` value that `cond` is known to have at the start of `block`.
+struct Fact<'tcx> {
+    block: BasicBlock,
+    cond: Value<'tcx>,
+    value: bool,
+}
+
+struct Values<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    body: &'a Body<'tcx>,
+    ssa: &'a SsaLocals,
+    assignments: FxHashMap<Local, &'a Rvalue<'tcx>>,
+}
+
+impl<'a, 'tcx> Values<'a, 'tcx> {
+    fn operand(&self, operand: &Operand<'tcx>, depth: usize) -> Option<Value<'tcx>> {
+        match operand {
+            Operand::Constant(constant) => {
+                Some(Value::Int(constant.const_.try_eval_scalar_int(self.tcx, self.param_env)?))
+            }
+            Operand::Copy(place) | Operand::Move(place) => self.local(place.as_local()?, depth),
+        }
+    }
+
+    fn local(&self, local: Local, depth: usize) -> Option<Value<'tcx>> {
+        if !self.ssa.is_ssa(local) {
+            return None;
+        }
+        if let Some(depth) = depth.checked_sub(1)
+            && let Some(rvalue) = self.assignments.get(&local)
+            && let Some(value) = self.rvalue(rvalue, depth)
+        {
+            return Some(value);
+        }
+        Some(Value::Opaque(local))
+    }
+
+    fn rvalue(&self, rvalue: &Rvalue<'tcx>, depth: usize) -> Option<Value<'tcx>> {
+        match rvalue {
+            Rvalue::Use(operand) => self.operand(operand, depth),
+            Rvalue::UnaryOp(op, operand) => {
+                Some(Value::Unary(*op, Box::new(self.operand(operand, depth)?)))
+            }
+            Rvalue::BinaryOp(op, box (lhs, rhs)) => Some(Value::Binary(
+                *op,
+                Box::new(self.operand(lhs, depth)?),
+                Box::new(self.operand(rhs, depth)?),
+            )),
+            Rvalue::Len(place) => {
+                if let ty::Array(_, len) = place.ty(self.body, self.tcx).ty.kind() {
+                    let len = len.try_eval_target_usize(self.tcx, self.param_env)?;
+                    return Some(Value::Int(ScalarInt::try_from_target_usize(len, self.tcx)?));
+                }
+                // The length of a slice is stored in the pointer to it, so it cannot change as
+                // long as the pointer is not modified.
+                if !matches!(place.projection[..], [] | [ProjectionElem::Deref]) {
+                    return None;
+                }
+                self.ssa.is_ssa(place.local).then_some(Value::Len(*place))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn collect_facts<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    values: &Values<'_, 'tcx>,
+) -> Vec<Fact<'tcx>> {
+    let predecessors = body.basic_blocks.predecessors();
+    let mut facts = Vec::new();
+    for (bb, data) in body.basic_blocks.iter_enumerated() {
+        if data.is_cleanup {
+            continue;
+        }
+        // The fact only holds in `block` if it cannot be entered from anywhere else. This also
+        // rejects blocks that are reached by several edges of the same `switchInt`.
+        let mut push = |block: BasicBlock, cond: Value<'tcx>, value: bool| {
+            if predecessors[block].as_slice() == [bb] {
+                facts.push(Fact { block, cond, value });
+            }
+        };
+        match data.terminator().kind {
+            TerminatorKind::SwitchInt { ref discr, ref targets } => {
+                if !discr.ty(&body.local_decls, tcx).is_bool() {
+                    continue;
+                }
+                let Some(cond) = values.operand(discr, MAX_DEPTH) else { continue };
+                let mut iter = targets.iter();
+                let (Some((value, target)), None) = (iter.next(), iter.next()) else { continue };
+                push(target, cond.clone(), value != 0);
+                push(targets.otherwise(), cond, value == 0);
+            }
+            TerminatorKind::Assert { ref cond, expected, target, .. } => {
+                let Some(cond) = values.operand(cond, MAX_DEPTH) else { continue };
+                push(target, cond, expected);
+            }
+            _ => {}
+        }
+    }
+    facts
+}
@@ -1,8 +1,9 @@
+use rustc_data_structures::fx::FxHashMap;
 use rustc_index::bit_set::BitSet;
 use rustc_index::IndexSlice;
 use rustc_middle::mir::visit::*;
 use rustc_middle::mir::*;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{ParamEnv, TyCtxt};
 use tracing::{debug, instrument};
 
 use crate::ssa::SsaLocals;
@@ -52,12 +53,140 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         }
         .visit_body_preserves_cfg(body);
 
-        if any_replacement {
+        let any_field_replacement =
+            tcx.sess.mir_opt_level() >= 3 && propagate_field_copies(tcx, body, param_env);
+
+        if any_replacement || any_field_replacement {
             crate::simplify::remove_unused_definitions(body);
         }
     }
 }
 
+/// Replace locals that copy fields of another local by those fields.
+///
+/// We consider patterns of the form
+///   _a = rvalue
+///   _b = copy (_a.0)
+///   _c = copy (_b.1)
+/// where each of the locals is only assigned once, and `_a` is neither borrowed nor moved from.
+/// `_a` then holds the same value at each use of `_b` and `_c`, so we replace them by reads of
+/// `_a.0` and `_a.0.1`.
+///
+/// `_a` may be used outside of its storage with this, so we remove its storage statements.
+#[instrument(level = "trace", skip(tcx, body, param_env))]
+fn propagate_field_copies<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &mut Body<'tcx>,
+    param_env: ParamEnv<'tcx>,
+) -> bool {
+    let ssa = SsaLocals::new(tcx, body, param_env);
+
+    let mut moved = MovedLocals { moved: BitSet::new_empty(body.local_decls.len()) };
+    moved.visit_body(body);
+    let moved = moved.moved;
+
+    let mut fields: FxHashMap<Local, Place<'tcx>> = FxHashMap::default();
+    let mut storage_to_remove = BitSet::new_empty(body.local_decls.len());
+    for (local, rvalue, _) in ssa.assignments(body) {
+        let Rvalue::Use(Operand::Copy(place)) = rvalue else { continue };
+        if place.projection.is_empty()
+            || !place.projection.iter().all(|elem| matches!(elem, ProjectionElem::Field(..)))
+            || ssa.borrowed_locals().contains(local)
+        {
+            continue;
+        }
+        let field = if let Some(base) = fields.get(&place.local) {
+            base.project_deeper(place.projection, tcx)
+        } else if ssa.is_ssa(place.local)
+            && !ssa.borrowed_locals().contains(place.local)
+            && !moved.contains(place.local)
+        {
+            *place
+        } else {
+            continue;
+        };
+        debug!(?local, ?field);
+        storage_to_remove.insert(field.local);
+        fields.insert(local, field);
+    }
+
+    if fields.is_empty() {
+        return false;
+    }
+
+    FieldReplacer { tcx, fields, storage_to_remove }.visit_body_preserves_cfg(body);
+    true
+}
+
+/// Collects the locals that are moved from, fully or in part.
+struct MovedLocals {
+    moved: BitSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for MovedLocals {
+    fn visit_operand(&mut self, operand: &Operand<'tcx>, loc: Location) {
+        if let Operand::Move(place) = operand
+            && !place.is_indirect_first_projection()
+        {
+            self.moved.insert(place.local);
+        }
+        self.super_operand(operand, loc);
+    }
+}
+
+struct FieldReplacer<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    fields: FxHashMap<Local, Place<'tcx>>,
+    storage_to_remove: BitSet<Local>,
+}
+
+impl<'tcx> FieldReplacer<'tcx> {
+    fn replace(&self, place: &mut Place<'tcx>) -> bool {
+        // Derefs must stay at the start of the projection.
+        if place.is_indirect() {
+            return false;
+        }
+        let Some(field) = self.fields.get(&place.local) else { return false };
+        *place = field.project_deeper(place.projection, self.tcx);
+        true
+    }
+}
+
+impl<'tcx> MutVisitor<'tcx> for FieldReplacer<'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_place(&mut self, place: &mut Place<'tcx>, ctxt: PlaceContext, loc: Location) {
+        if ctxt == PlaceContext::NonUse(NonUseContext::VarDebugInfo) && self.replace(place) {
+            return;
+        }
+        self.super_place(place, ctxt, loc);
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, loc: Location) {
+        if let Operand::Copy(place) | Operand::Move(place) = operand {
+            let mut place = *place;
+            if self.replace(&mut place) {
+                // The field may be read again, so it must not be moved from.
+                *operand = Operand::Copy(place);
+                return;
+            }
+        }
+        self.super_operand(operand, loc);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'tcx>, loc: Location) {
+        if let StatementKind::StorageLive(l) | StatementKind::StorageDead(l) = stmt.kind
+            && self.storage_to_remove.contains(l)
+        {
+            stmt.make_nop();
+            return;
+        }
+        self.super_statement(stmt, loc);
+    }
+}
+
 /// `SsaLocals` computed equivalence classes between locals considering copy/move assignments.
 ///
 /// This function also returns whether all the `move?` in the pattern are `move` and not copies.
@@ -1,3 +1,4 @@
+use rustc_hir::def_id::LocalDefId;
 use rustc_middle::bug;
 use rustc_middle::mir::visit::*;
 use rustc_middle::mir::*;
@@ -10,6 +11,19 @@
 const LARGE_SWITCH_PENALTY: usize = 20;
 const CONST_SWITCH_BONUS: usize = 10;
 
+pub(super) fn mir_estimated_size(tcx: TyCtxt<'_>, def_id: LocalDefId) -> usize {
+    if !tcx.def_kind(def_id).is_fn_like() || !tcx.is_mir_available(def_id) {
+        return 0;
+    }
+    let body = tcx.optimized_mir(def_id);
+    let param_env = tcx.param_env_reveal_all_normalized(def_id);
+    let mut checker = CostChecker::new(tcx, param_env, None, body);
+    checker.visit_body(body);
+    // The bonuses only predict what simplifications inlining enables, e.g. that an `unreachable`
+    // branch goes away, so they must not make the body itself look smaller.
+    checker.penalty / INSTR_COST
+}
+
 /// Verify that the callee body is compatible with the caller.
 #[derive(Clone)]
 pub(super) struct CostChecker<'b, 'tcx> {
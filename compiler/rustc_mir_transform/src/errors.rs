@@ -38,6 +38,25 @@ pub(crate) struct UnalignedPackedRef {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(mir_transform_slow_mir_pass)]
+#[note]
+pub(crate) struct SlowMirPass {
+    #[primary_span]
+    pub span: Span,
+    pub pass: &'static str,
+    pub elapsed: String,
+    pub timeout: u64,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_mir_pass_panicked)]
+pub(crate) struct MirPassPanicked {
+    pub pass: &'static str,
+    pub body: String,
+    pub checking: bool,
+}
+
 pub(crate) struct AssertLint<P> {
     pub span: Span,
     pub assert_kind: AssertKind<P>,
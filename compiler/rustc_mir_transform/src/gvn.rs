@@ -1200,6 +1200,11 @@ fn simplify_binary(
         if let Some(value) = self.simplify_binary_inner(op, lhs_ty, lhs, rhs) {
             return Some(value);
         }
+        // Order the operands of commutative operations, so that `a + b` and `b + a` get the same
+        // value number. Float operations are left alone, they may pick the NaN of either operand.
+        if op.is_commutative() && !lhs_ty.is_floating_point() && lhs > rhs {
+            std::mem::swap(&mut lhs, &mut rhs);
+        }
         let value = Value::BinaryOp(op, lhs, rhs);
         Some(self.insert(value))
     }
@@ -97,12 +97,15 @@ fn inline<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) -> bool {
 
     let param_env = tcx.param_env_reveal_all_normalized(def_id);
     let codegen_fn_attrs = tcx.codegen_fn_attrs(def_id);
+    let max_depth =
+        tcx.sess.opts.unstable_opts.inline_mir_depth_limit.unwrap_or(TOP_DOWN_DEPTH_LIMIT);
 
     let mut this = Inliner {
         tcx,
         param_env,
         codegen_fn_attrs,
         history: Vec::new(),
+        max_depth,
         changed: false,
         caller_is_inline_forwarder: matches!(
             codegen_fn_attrs.inline,
@@ -125,6 +128,10 @@ struct Inliner<'tcx> {
     /// The number of `DefId`s is finite, so checking history is enough
     /// to ensure that we do not loop endlessly while inlining.
     history: Vec<DefId>,
+    /// How deep `history` may grow. Each call site we inline is processed again with its callee
+    /// pushed on the stack, so this bounds the depth of each chain of inlined calls, while sibling
+    /// call sites are considered independently.
+    max_depth: usize,
     /// Indicates that the caller body has been modified.
     changed: bool,
     /// Indicates that the caller is #[inline] and just calls another function,
@@ -138,7 +145,7 @@ fn process_blocks(&mut self, caller_body: &mut Body<'tcx>, blocks: Range<BasicBl
         // to prevent super-linear growth in MIR size
         let inline_limit = match self.history.len() {
             0 => usize::MAX,
-            1..=TOP_DOWN_DEPTH_LIMIT => 1,
+            depth if depth <= self.max_depth => 1,
             _ => return,
         };
         let mut inlined_count = 0;
@@ -1,8 +1,10 @@
 //! Performs various peephole optimizations.
 
 use rustc_ast::attr;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::LangItem;
 use rustc_middle::bug;
+use rustc_middle::mir::visit::{NonMutatingUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::*;
 use rustc_middle::ty::layout::ValidityRequirement;
 use rustc_middle::ty::{self, layout, GenericArgsRef, ParamEnv, Ty, TyCtxt};
@@ -39,6 +41,7 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let preserve_ub_checks =
             attr::contains_name(tcx.hir().krate_attrs(), sym::rustc_preserve_ub_checks);
         for block in body.basic_blocks.as_mut() {
+            let mut element_ptrs = FxHashMap::default();
             for statement in block.statements.iter_mut() {
                 match statement.kind {
                     StatementKind::Assign(box (_place, ref mut rvalue)) => {
@@ -53,9 +56,14 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                     }
                     _ => {}
                 }
+                ctx.simplify_element_ptr_read(&mut element_ptrs, statement);
             }
 
             ctx.simplify_primitive_clone(block.terminator.as_mut().unwrap(), &mut block.statements);
+            ctx.simplify_saturating_identity(
+                block.terminator.as_mut().unwrap(),
+                &mut block.statements,
+            );
             ctx.simplify_intrinsic_assert(block.terminator.as_mut().unwrap());
             ctx.simplify_nounwind_call(block.terminator.as_mut().unwrap());
             simplify_duplicate_switch_targets(block.terminator.as_mut().unwrap());
@@ -193,6 +201,101 @@ fn simplify_ptr_aggregate(&self, source_info: &SourceInfo, rvalue: &mut Rvalue<'
         }
     }
 
+    /// Transform `_p = &raw const A; _q = _p as *const T; _r = Offset(_q, const N); _x = *_r`
+    /// ==> `_x = A[N]` when `A` is an array, and the statements are in the same block.
+    ///
+    /// `element_ptrs` tracks the locals of the current block that hold a pointer to an element of
+    /// an array local.
+    fn simplify_element_ptr_read(
+        &self,
+        element_ptrs: &mut FxHashMap<Local, ElementPtr>,
+        statement: &mut Statement<'tcx>,
+    ) {
+        // Forget about the pointers and arrays that this statement may modify.
+        let mut touched = TouchedLocals::default();
+        touched.visit_statement(statement, Location::START);
+        element_ptrs.retain(|local, ptr| {
+            !touched.locals.contains(local) && !touched.locals.contains(&ptr.array)
+        });
+
+        let StatementKind::Assign(box (place, ref mut rvalue)) = statement.kind else { return };
+
+        // Replace a read through a known pointer.
+        if let Rvalue::Use(Operand::Copy(read) | Operand::Move(read)) = *rvalue
+            && let [ProjectionElem::Deref, ref rest @ ..] = read.projection[..]
+            && let Some(ptr) = element_ptrs.get(&read.local)
+            && ptr.is_element
+            && ptr.offset < ptr.len
+            && self.should_simplify_custom(&statement.source_info, "Rvalue", &rvalue)
+        {
+            let mut projection = vec![ProjectionElem::ConstantIndex {
+                offset: ptr.offset,
+                min_length: ptr.len,
+                from_end: false,
+            }];
+            projection.extend_from_slice(rest);
+            let new_place =
+                Place { local: ptr.array, projection: self.tcx.mk_place_elems(&projection) };
+            let operand = if let Rvalue::Use(Operand::Move(_)) = rvalue {
+                Operand::Move(new_place)
+            } else {
+                Operand::Copy(new_place)
+            };
+            *rvalue = Rvalue::Use(operand);
+            return;
+        }
+
+        let Some(dest) = place.as_local() else { return };
+        let ptr = match *rvalue {
+            Rvalue::RawPtr(_, array) if let Some(array) = array.as_local() => {
+                let ty::Array(_, len) = *self.local_decls[array].ty.kind() else { return };
+                let Some(len) = len.try_eval_target_usize(self.tcx, self.param_env) else {
+                    return;
+                };
+                ElementPtr { array, len, offset: 0, is_element: false }
+            }
+            Rvalue::Use(Operand::Copy(ptr) | Operand::Move(ptr)) => {
+                let Some(&ptr) = ptr.as_local().and_then(|ptr| element_ptrs.get(&ptr)) else {
+                    return;
+                };
+                ptr
+            }
+            // Casting the array pointer to a pointer to its element type.
+            Rvalue::Cast(CastKind::PtrToPtr, ref operand, cast_ty)
+                if let Some(ptr) = operand.place().and_then(|p| p.as_local())
+                    && let Some(&ptr) = element_ptrs.get(&ptr)
+                    && !ptr.is_element =>
+            {
+                let array_ty = self.local_decls[ptr.array].ty;
+                if cast_ty.builtin_deref(true) != Some(array_ty.sequence_element_type(self.tcx)) {
+                    return;
+                }
+                ElementPtr { is_element: true, ..ptr }
+            }
+            Rvalue::BinaryOp(BinOp::Offset, box (ref operand, Operand::Constant(ref delta)))
+                if let Some(ptr) = operand.place().and_then(|p| p.as_local())
+                    && let Some(&ptr) = element_ptrs.get(&ptr)
+                    && ptr.is_element =>
+            {
+                let Some(bits) = delta.const_.try_eval_bits(self.tcx, self.param_env) else {
+                    return;
+                };
+                let delta = if delta.ty().is_signed() {
+                    let size = self.tcx.data_layout.pointer_size;
+                    let Ok(delta) = u64::try_from(size.sign_extend(bits)) else { return };
+                    delta
+                } else {
+                    let Ok(delta) = u64::try_from(bits) else { return };
+                    delta
+                };
+                let Some(offset) = ptr.offset.checked_add(delta) else { return };
+                ElementPtr { offset, ..ptr }
+            }
+            _ => return,
+        };
+        element_ptrs.insert(dest, ptr);
+    }
+
     fn simplify_ub_check(&self, source_info: &SourceInfo, rvalue: &mut Rvalue<'tcx>) {
         if let Rvalue::NullaryOp(NullOp::UbChecks, _) = *rvalue {
             let const_ = Const::from_bool(self.tcx, self.tcx.sess.ub_checks());
@@ -309,6 +412,49 @@ fn simplify_primitive_clone(
         terminator.kind = TerminatorKind::Goto { target: destination_block };
     }
 
+    /// Transform `saturating_add(x, 0)`, `saturating_add(0, x)` and `saturating_sub(x, 0)` ==> `x`.
+    ///
+    /// The other arithmetic intrinsics are lowered to `BinaryOp`s, whose neutral elements are
+    /// handled by GVN.
+    fn simplify_saturating_identity(
+        &self,
+        terminator: &mut Terminator<'tcx>,
+        statements: &mut Vec<Statement<'tcx>>,
+    ) {
+        let TerminatorKind::Call { func, args, destination, target, .. } = &terminator.kind else {
+            return;
+        };
+        let Some(destination_block) = *target else { return };
+        let func_ty = func.ty(self.local_decls, self.tcx);
+        let Some((intrinsic_name, _)) = resolve_rust_intrinsic(self.tcx, func_ty) else {
+            return;
+        };
+        let [lhs, rhs] = &args[..] else { return };
+
+        let is_zero = |operand: &Operand<'tcx>| {
+            operand
+                .constant()
+                .and_then(|c| c.const_.try_eval_bits(self.tcx, self.param_env))
+                .is_some_and(|bits| bits == 0)
+        };
+        let operand = match intrinsic_name {
+            sym::saturating_add if is_zero(&rhs.node) => &lhs.node,
+            sym::saturating_add if is_zero(&lhs.node) => &rhs.node,
+            sym::saturating_sub if is_zero(&rhs.node) => &lhs.node,
+            _ => return,
+        };
+
+        if !self.should_simplify_custom(&terminator.source_info, "Call", intrinsic_name) {
+            return;
+        }
+
+        statements.push(Statement {
+            source_info: terminator.source_info,
+            kind: StatementKind::Assign(Box::new((*destination, Rvalue::Use(operand.clone())))),
+        });
+        terminator.kind = TerminatorKind::Goto { target: destination_block };
+    }
+
     fn simplify_nounwind_call(&self, terminator: &mut Terminator<'tcx>) {
         let TerminatorKind::Call { func, unwind, .. } = &mut terminator.kind else {
             return;
@@ -364,6 +510,35 @@ fn simplify_intrinsic_assert(&self, terminator: &mut Terminator<'tcx>) {
     }
 }
 
+/// A raw pointer into an array local, see `simplify_element_ptr_read`.
+#[derive(Copy, Clone)]
+struct ElementPtr {
+    array: Local,
+    len: u64,
+    /// Offset of the pointee in the array, in elements.
+    offset: u64,
+    /// Whether the pointer was cast to a pointer to the element type. `offset` is always 0 before.
+    is_element: bool,
+}
+
+/// Collects the locals that a statement writes to, moves out of, or changes the storage of.
+#[derive(Default)]
+struct TouchedLocals {
+    locals: FxHashSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for TouchedLocals {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _: Location) {
+        if !place.is_indirect()
+            && (context.is_mutating_use()
+                || context.is_storage_marker()
+                || context == PlaceContext::NonMutatingUse(NonMutatingUseContext::Move))
+        {
+            self.locals.insert(place.local);
+        }
+    }
+}
+
 fn intrinsic_assert_panics<'tcx>(
     tcx: TyCtxt<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
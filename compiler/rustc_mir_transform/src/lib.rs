@@ -42,14 +42,17 @@
 mod pass_manager;
 
 use pass_manager::{self as pm, Lint, MirLint, MirPass, WithMinOptLevel};
+pub use validate::{validate_mir, MirError};
 
 mod abort_unwinding_calls;
 mod add_call_guards;
 mod add_moves_for_packed_drops;
 mod add_retag;
 mod add_subtyping_projections;
+mod bounds_check_elim;
 mod check_alignment;
 mod check_const_item_mutation;
+mod check_null;
 mod check_packed_ref;
 // This pass is public to allow external drivers to perform MIR cleanup
 pub mod cleanup_post_borrowck;
@@ -80,13 +83,16 @@
 mod jump_threading;
 mod known_panics_lint;
 mod large_enums;
+mod licm;
 mod lint;
+mod loop_unroll;
 mod lower_intrinsics;
 mod lower_slice_len;
 mod match_branches;
 mod mentioned_items;
 mod multiple_return_terminators;
 mod nrvo;
+mod panic_handler;
 mod post_drop_elaboration;
 mod prettify;
 mod promote_consts;
@@ -108,6 +114,7 @@
 mod simplify_comparison_integral;
 mod single_use_consts;
 mod sroa;
+mod tail_call;
 mod unreachable_enum_branching;
 mod unreachable_prop;
 mod validate;
@@ -134,6 +141,7 @@ pub fn provide(providers: &mut Providers) {
         mir_inliner_callees: inline::cycle::mir_inliner_callees,
         promoted_mir,
         deduced_param_attrs: deduce_param_attrs::deduced_param_attrs,
+        mir_estimated_size: cost_checker::mir_estimated_size,
         coroutine_by_move_body_def_id: coroutine::coroutine_by_move_body_def_id,
         ..providers.queries
     };
@@ -569,6 +577,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
         &[
             // Add some UB checks before any UB gets optimized away.
             &check_alignment::CheckAlignment,
+            &check_null::CheckNull,
             // Before inlining: trim down MIR with passes to reduce inlining work.
 
             // Has to be done before inlining, otherwise actual call will be almost always inlined.
@@ -585,6 +594,9 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             // Inlining and instantiation may introduce ZST and useless drops.
             &remove_zsts::RemoveZsts,
             &remove_unneeded_drops::RemoveUnneededDrops,
+            // Removing the drops of moved values above may leave recursive calls directly followed
+            // by a `return`.
+            &tail_call::TailCallOptimization,
             // Type instantiation may create uninhabited enums.
             // Also eliminates some unreachable branches based on variants of enums.
             &unreachable_enum_branching::UnreachableEnumBranching,
@@ -601,10 +613,14 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             // optimizations.
             &instsimplify::InstSimplify::AfterSimplifyCfg,
             &simplify::SimplifyLocals::BeforeConstProp,
+            // Unroll before GVN, so it can fold the body of each iteration.
+            &loop_unroll::LoopUnrolling,
             &dead_store_elimination::DeadStoreElimination::Initial,
             &gvn::GVN,
             &simplify::SimplifyLocals::AfterGVN,
             &dataflow_const_prop::DataflowConstProp,
+            &licm::LoopInvariantCodeMotion,
+            &bounds_check_elim::BoundsCheckElimination,
             &single_use_consts::SingleUseConsts,
             &o1(simplify_branches::SimplifyConstCondition::AfterConstProp),
             &jump_threading::JumpThreading,
@@ -621,6 +637,8 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &multiple_return_terminators::MultipleReturnTerminators,
             &deduplicate_blocks::DeduplicateBlocks,
             &large_enums::EnumSizeOpt { discrepancy: 128 },
+            // After the passes that may remove or fold `assert`s.
+            &panic_handler::PanicHandlerPass,
             // Some cleanup necessary at least for LLVM and potentially other codegen backends.
             &add_call_guards::CriticalCallEdges,
             // Cleanup for human readability, off by default.
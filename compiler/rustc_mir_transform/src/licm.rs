@@ -0,0 +1,219 @@
+//! Loop-invariant code motion (LICM).
+//!
+//! This pass finds natural loops using the dominator tree: an edge `tail -> header` is a back-edge
+//! when `header` dominates `tail`, and the loop is made of `header` and every block that can reach
+//! `tail` without going through `header`.
+//!
+//! Inside each loop, an assignment `_l = rvalue` is moved to the pre-header of the loop when:
+//! - `rvalue` has no side effects and cannot cause UB, whatever the values of its operands are,
+//!   since the hoisted statement may execute in cases where the original one would not;
+//! - all the operands of `rvalue` are constants, SSA locals that are not modified in the loop, or
+//!   locals that were themselves hoisted out of this loop;
+//! - `_l` is an SSA local, so its only assignment dominates all its uses, and every use of `_l`
+//!   sees the same value before and after the transformation.
+//!
+//! The pre-header is the only predecessor of the header outside the loop, and must end in a
+//! `goto`. We do not create pre-headers, so loops without one are left alone.
+//!
+//! A hoisted local is live across the whole loop after the transformation, so its `StorageLive`
+//! and `StorageDead` statements are removed.
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::visit::{PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use tracing::{debug, instrument, trace};
+
+use crate::ssa::SsaLocals;
+
+pub(super) struct LoopInvariantCodeMotion;
+
+impl<'tcx> crate::MirPass<'tcx> for LoopInvariantCodeMotion {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+
+        let mut loops = find_loops(body);
+        if loops.is_empty() {
+            return;
+        }
+        // Visit inner loops first, so that what we hoist from them can be considered again when
+        // visiting the enclosing loop.
+        loops.sort_by_key(|(_, blocks)| blocks.count());
+
+        // Moving a statement to a block that dominates it keeps the local SSA, so we can compute
+        // this once for all the loops.
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+        let ssa = SsaLocals::new(tcx, body, param_env);
+
+        let mut hoisted_locals = BitSet::new_empty(body.local_decls.len());
+        for (header, blocks) in loops {
+            let Some(preheader) = find_preheader(body, header, &blocks) else { continue };
+            trace!(?header, ?preheader, ?blocks);
+
+            let mut modified = ModifiedLocals {
+                locals: BitSet::new_empty(body.local_decls.len()),
+            };
+            for bb in blocks.iter() {
+                modified.visit_basic_block_data(bb, &body.basic_blocks[bb]);
+            }
+
+            // Visit in reverse postorder, so we see the definition of a local before its uses.
+            let mut hoisted = Vec::new();
+            let mut hoisted_in_loop = BitSet::new_empty(body.local_decls.len());
+            for &bb in body.basic_blocks.reverse_postorder() {
+                if !blocks.contains(bb) || body.basic_blocks[bb].is_cleanup {
+                    continue;
+                }
+                for (statement_index, statement) in
+                    body.basic_blocks[bb].statements.iter().enumerate()
+                {
+                    let StatementKind::Assign(box (place, ref rvalue)) = statement.kind else {
+                        continue;
+                    };
+                    let Some(local) = place.as_local() else { continue };
+                    if body.local_kind(local) != LocalKind::Temp || !ssa.is_ssa(local) {
+                        continue;
+                    }
+
+                    let is_invariant = |local| {
+                        hoisted_in_loop.contains(local)
+                            || (ssa.is_ssa(local) && !modified.locals.contains(local))
+                    };
+                    if is_invariant_rvalue(rvalue, is_invariant) {
+                        debug!(?local, ?rvalue, "hoisting");
+                        hoisted_in_loop.insert(local);
+                        hoisted_locals.insert(local);
+                        hoisted.push(Location { block: bb, statement_index });
+                    }
+                }
+            }
+
+            let basic_blocks = body.basic_blocks.as_mut_preserves_cfg();
+            for Location { block, statement_index } in hoisted {
+                let statement = &mut basic_blocks[block].statements[statement_index];
+                let moved = statement.clone();
+                statement.make_nop();
+                basic_blocks[preheader].statements.push(moved);
+            }
+        }
+
+        if hoisted_locals.is_empty() {
+            return;
+        }
+        for data in body.basic_blocks.as_mut_preserves_cfg() {
+            for statement in data.statements.iter_mut() {
+                if let StatementKind::StorageLive(local) | StatementKind::StorageDead(local) =
+                    statement.kind
+                    && hoisted_locals.contains(local)
+                {
+                    statement.make_nop();
+                }
+            }
+        }
+    }
+}
+
+/// Returns the header and blocks of each natural loop in the body. Back-edges that share the same
+/// header are merged into a single loop.
+pub(super) fn find_loops(body: &Body<'_>) -> Vec<(BasicBlock, BitSet<BasicBlock>)> {
+    let dominators = body.basic_blocks.dominators();
+    let predecessors = body.basic_blocks.predecessors();
+
+    let mut loops: FxIndexMap<BasicBlock, BitSet<BasicBlock>> = FxIndexMap::default();
+    for (tail, data) in body.basic_blocks.iter_enumerated() {
+        if !dominators.is_reachable(tail) {
+            continue;
+        }
+        for header in data.terminator().successors() {
+            if !dominators.dominates(header, tail) {
+                continue;
+            }
+
+            let blocks = loops.entry(header).or_insert_with(|| {
+                let mut blocks = BitSet::new_empty(body.basic_blocks.len());
+                blocks.insert(header);
+                blocks
+            });
+            let mut stack = vec![tail];
+            while let Some(bb) = stack.pop() {
+                if blocks.insert(bb) {
+                    stack.extend(predecessors[bb].iter().copied());
+                }
+            }
+        }
+    }
+    loops.into_iter().collect()
+}
+
+/// The pre-header is the single block outside the loop that jumps to the loop header.
+pub(super) fn find_preheader(
+    body: &Body<'_>,
+    header: BasicBlock,
+    blocks: &BitSet<BasicBlock>,
+) -> Option<BasicBlock> {
+    let mut outside = body.basic_blocks.predecessors()[header]
+        .iter()
+        .copied()
+        .filter(|&pred| !blocks.contains(pred));
+    let preheader = outside.next()?;
+    if outside.next().is_some() {
+        return None;
+    }
+
+    let data = &body.basic_blocks[preheader];
+    if data.is_cleanup || !matches!(data.terminator().kind, TerminatorKind::Goto { .. }) {
+        return None;
+    }
+    Some(preheader)
+}
+
+/// Whether `rvalue` can be evaluated anywhere in the loop and always produce the same value.
+fn is_invariant_rvalue(rvalue: &Rvalue<'_>, is_invariant: impl Fn(Local) -> bool) -> bool {
+    let is_invariant_operand = |operand: &Operand<'_>| match operand {
+        Operand::Constant(_) => true,
+        Operand::Copy(place) | Operand::Move(place) => {
+            place.as_local().is_some_and(|local| is_invariant(local))
+        }
+    };
+
+    match rvalue {
+        // Division and remainder are UB when the divisor is zero, and the unchecked operators
+        // and `Offset` have preconditions. They are only valid where the original code placed
+        // them.
+        Rvalue::BinaryOp(
+            BinOp::Div
+            | BinOp::Rem
+            | BinOp::Offset
+            | BinOp::AddUnchecked
+            | BinOp::SubUnchecked
+            | BinOp::MulUnchecked
+            | BinOp::ShlUnchecked
+            | BinOp::ShrUnchecked,
+            _,
+        ) => false,
+        Rvalue::BinaryOp(_, box (lhs, rhs)) => {
+            is_invariant_operand(lhs) && is_invariant_operand(rhs)
+        }
+        Rvalue::UnaryOp(UnOp::Not | UnOp::Neg, operand) => is_invariant_operand(operand),
+        _ => false,
+    }
+}
+
+/// Collects the locals that are written to, or whose storage is changed, in a set of blocks.
+struct ModifiedLocals {
+    locals: BitSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for ModifiedLocals {
+    fn visit_local(&mut self, local: Local, context: PlaceContext, _: Location) {
+        if context.is_mutating_use() || context.is_storage_marker() {
+            self.locals.insert(local);
+        }
+    }
+}
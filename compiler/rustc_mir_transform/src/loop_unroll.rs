@@ -0,0 +1,439 @@
+//! Fully unrolls loops with a small constant trip count.
+//!
+//! We look for natural loops, as found by `LoopInvariantCodeMotion`, whose header ends in
+//! `switchInt(_c) -> [0: exit, otherwise: body]`, where `_c = Lt(copy _i, const N)`, possibly
+//! through a copy of `_i`, and:
+//! - `_i` is an unsigned integer that is not borrowed;
+//! - the pre-header of the loop ends by assigning `_i = const K`;
+//! - the only modification of `_i` in the loop is `_i = Add(copy _i, const 1)`, in a block that is
+//!   executed exactly once by each iteration that goes back to the header;
+//! - the header is the only block that leaves the loop, other than by unwinding.
+//!
+//! which is what `while i < N { ..; i += 1 }` looks like without overflow checks. Such a loop runs
+//! its body exactly `N - K` times, unless it panics. A loop that can also be left by a `break` or a
+//! `return` often runs fewer iterations, and unrolling it would mostly grow the code. When `N - K`
+//! is at most `-Z unroll-loops-threshold`, we replace the loop by `N - K` copies of its blocks,
+//! chained together, followed by a copy of the header that goes to the exit. In each copy, the
+//! header jumps directly to the body, the increment becomes an assignment of its constant result,
+//! and the reads of `_i` are replaced by the constant value they observe, when that value does not
+//! depend on the path taken through the body. The following passes can then fold the body of each
+//! iteration.
+//!
+//! The body of the loop must not contain other cycles, so every block is executed at most once per
+//! iteration. Loops are visited from the innermost, so a loop whose inner loops were unrolled can
+//! be unrolled in turn.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_index::bit_set::BitSet;
+use rustc_middle::bug;
+use rustc_middle::mir::interpret::Scalar;
+use rustc_middle::mir::visit::{MutVisitor, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, ParamEnv, Ty, TyCtxt};
+use rustc_mir_dataflow::impls::borrowed_locals;
+use rustc_span::Span;
+use tracing::{debug, instrument, trace};
+
+use crate::licm::{find_loops, find_preheader};
+
+pub(super) struct LoopUnrolling;
+
+/// The maximum trip count of the loops we unroll, unless `-Z unroll-loops-threshold` is given.
+const DEFAULT_THRESHOLD: u128 = 8;
+
+/// The maximum number of statements in the unrolled copies of a loop.
+const MAX_UNROLLED_STATEMENTS: usize = 256;
+
+impl<'tcx> crate::MirPass<'tcx> for LoopUnrolling {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+
+        let threshold = match tcx.sess.opts.unstable_opts.unroll_loops_threshold {
+            Some(threshold) => threshold as u128,
+            None => DEFAULT_THRESHOLD,
+        };
+        if threshold == 0 {
+            return;
+        }
+
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+        // The copies only borrow the locals that the original loop borrowed.
+        let borrowed = borrowed_locals(body);
+
+        // An unrolled loop is replaced by acyclic code, so there is one loop less after each
+        // iteration.
+        loop {
+            let mut loops = find_loops(body);
+            loops.sort_by_key(|(_, blocks)| blocks.count());
+            let Some(counted) = loops.into_iter().find_map(|(header, blocks)| {
+                CountedLoop::new(tcx, param_env, body, &borrowed, header, &blocks, threshold)
+            }) else {
+                break;
+            };
+            debug!(header = ?counted.header, trip_count = counted.trip_count, "unrolling");
+            counted.unroll(tcx, body);
+            crate::simplify::remove_dead_blocks(body);
+        }
+    }
+}
+
+struct CountedLoop<'tcx> {
+    header: BasicBlock,
+    preheader: BasicBlock,
+    /// The blocks of the loop in reverse postorder, starting with the header.
+    blocks: Vec<BasicBlock>,
+    /// The target of the header when the condition holds.
+    body_target: BasicBlock,
+    exit: BasicBlock,
+    counter: Local,
+    counter_ty: Ty<'tcx>,
+    increment: Location,
+    increment_span: Span,
+    start: u128,
+    trip_count: u128,
+}
+
+/// The value of the counter at some point of an iteration.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Phase {
+    BeforeIncrement,
+    AfterIncrement,
+    /// The block that contains the increment.
+    Increment,
+    /// The block can be reached both before and after the increment.
+    Unknown,
+}
+
+impl<'tcx> CountedLoop<'tcx> {
+    fn new(
+        tcx: TyCtxt<'tcx>,
+        param_env: ParamEnv<'tcx>,
+        body: &Body<'tcx>,
+        borrowed: &BitSet<Local>,
+        header: BasicBlock,
+        loop_blocks: &BitSet<BasicBlock>,
+        threshold: u128,
+    ) -> Option<CountedLoop<'tcx>> {
+        let header_data = &body.basic_blocks[header];
+        if header_data.is_cleanup {
+            return None;
+        }
+        let TerminatorKind::SwitchInt { ref discr, ref targets } = header_data.terminator().kind
+        else {
+            return None;
+        };
+        let mut iter = targets.iter();
+        let (Some((0, exit)), None) = (iter.next(), iter.next()) else { return None };
+        let body_target = targets.otherwise();
+        if loop_blocks.contains(exit) || !loop_blocks.contains(body_target) {
+            return None;
+        }
+
+        // The condition must be the last value assigned to `_c` in the header. The comparison
+        // usually reads a copy of the counter, made earlier in the header.
+        let cond = discr.place()?.as_local()?;
+        if borrowed.contains(cond) {
+            return None;
+        }
+        let (cond_index, rvalue) = last_assignment(&header_data.statements, cond)?;
+        let Rvalue::BinaryOp(BinOp::Lt, box (lhs, rhs)) = rvalue else { return None };
+        let mut counter = lhs.place()?.as_local()?;
+        if let Some((_, Rvalue::Use(operand))) =
+            last_assignment(&header_data.statements[..cond_index], counter)
+        {
+            if borrowed.contains(counter) {
+                return None;
+            }
+            counter = operand.place()?.as_local()?;
+        }
+        let counter_ty = body.local_decls[counter].ty;
+        if !matches!(counter_ty.kind(), ty::Uint(_)) || borrowed.contains(counter) {
+            return None;
+        }
+        let size = counter_ty.primitive_size(tcx);
+        let eval = |operand: &Operand<'tcx>| {
+            operand.constant()?.const_.try_eval_scalar_int(tcx, param_env)?.try_to_bits(size).ok()
+        };
+        let end = eval(rhs)?;
+
+        // We need the blocks in an order where the definitions come before their uses, and this
+        // lets us check that the body of the loop has no other cycle, nor other exit.
+        let blocks: Vec<_> = body
+            .basic_blocks
+            .reverse_postorder()
+            .iter()
+            .copied()
+            .filter(|&bb| loop_blocks.contains(bb))
+            .collect();
+        if blocks.len() != loop_blocks.count() || blocks[0] != header {
+            return None;
+        }
+        let position: FxHashMap<_, _> = blocks.iter().enumerate().map(|(i, &bb)| (bb, i)).collect();
+        for &bb in &blocks {
+            for target in body.basic_blocks[bb].terminator().successors() {
+                if target != header
+                    && let Some(&target_position) = position.get(&target)
+                    && target_position <= position[&bb]
+                {
+                    trace!(?bb, ?target, "nested cycle");
+                    return None;
+                }
+                if bb != header
+                    && !position.contains_key(&target)
+                    && !body.basic_blocks[target].is_cleanup
+                {
+                    trace!(?bb, ?target, "early exit");
+                    return None;
+                }
+            }
+        }
+
+        let preheader = find_preheader(body, header, loop_blocks)?;
+        let initial = modifications(body, counter, preheader).last().copied()?;
+        let start =
+            match &body.basic_blocks[preheader].statements.get(initial.statement_index)?.kind {
+                StatementKind::Assign(box (place, Rvalue::Use(operand)))
+                    if place.as_local() == Some(counter) =>
+                {
+                    eval(operand)?
+                }
+                _ => return None,
+            };
+
+        let mut increments = blocks.iter().flat_map(|&bb| modifications(body, counter, bb));
+        let (Some(increment), None) = (increments.next(), increments.next()) else { return None };
+        if increment.block == header {
+            return None;
+        }
+        let increment_statement =
+            body.basic_blocks[increment.block].statements.get(increment.statement_index)?;
+        let StatementKind::Assign(box (place, Rvalue::BinaryOp(op, box (lhs, rhs)))) =
+            &increment_statement.kind
+        else {
+            return None;
+        };
+        if place.as_local() != Some(counter)
+            || !matches!(op, BinOp::Add | BinOp::AddUnchecked)
+            || lhs.place().and_then(|place| place.as_local()) != Some(counter)
+            || eval(rhs) != Some(1)
+        {
+            return None;
+        }
+
+        // Each iteration that goes back to the header must have incremented the counter.
+        let dominators = body.basic_blocks.dominators();
+        let latches_incremented = blocks.iter().all(|&bb| {
+            !body.basic_blocks[bb].terminator().successors().any(|target| target == header)
+                || dominators.dominates(increment.block, bb)
+        });
+        if !latches_incremented {
+            return None;
+        }
+
+        let trip_count = end.checked_sub(start)?;
+        if trip_count == 0 || trip_count > threshold {
+            return None;
+        }
+        let statements: usize =
+            blocks.iter().map(|&bb| body.basic_blocks[bb].statements.len()).sum();
+        if statements.saturating_mul(trip_count as usize) > MAX_UNROLLED_STATEMENTS {
+            return None;
+        }
+
+        Some(CountedLoop {
+            header,
+            preheader,
+            blocks,
+            body_target,
+            exit,
+            counter,
+            counter_ty,
+            increment,
+            increment_span: increment_statement.source_info.span,
+            start,
+            trip_count,
+        })
+    }
+
+    /// Which value of the counter each block of the loop observes when it starts executing.
+    fn phases(&self, body: &Body<'tcx>) -> FxHashMap<BasicBlock, Phase> {
+        let dominators = body.basic_blocks.dominators();
+        let mut reachable_from_increment = BitSet::new_empty(body.basic_blocks.len());
+        reachable_from_increment.insert(self.increment.block);
+        for &bb in &self.blocks {
+            if !reachable_from_increment.contains(bb) {
+                continue;
+            }
+            for target in body.basic_blocks[bb].terminator().successors() {
+                if target != self.header && self.blocks.contains(&target) {
+                    reachable_from_increment.insert(target);
+                }
+            }
+        }
+
+        self.blocks
+            .iter()
+            .map(|&bb| {
+                let phase = if bb == self.increment.block {
+                    Phase::Increment
+                } else if bb == self.header || !reachable_from_increment.contains(bb) {
+                    Phase::BeforeIncrement
+                } else if dominators.dominates(self.increment.block, bb) {
+                    Phase::AfterIncrement
+                } else {
+                    Phase::Unknown
+                };
+                (bb, phase)
+            })
+            .collect()
+    }
+
+    fn unroll(self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let phases = self.phases(body);
+        let position: FxHashMap<_, _> =
+            self.blocks.iter().enumerate().map(|(i, &bb)| (bb, i)).collect();
+        let trip_count = self.trip_count as usize;
+        let first_copy = body.basic_blocks.len();
+        // The header comes first, so the last copy, which only contains the header, is at
+        // `copy(trip_count, self.header)` as well.
+        let copy = |iteration: usize, bb: BasicBlock| {
+            BasicBlock::from_usize(first_copy + iteration * self.blocks.len() + position[&bb])
+        };
+        let size = self.counter_ty.primitive_size(tcx);
+        let value = |value: u128| {
+            Operand::const_from_scalar(
+                tcx,
+                self.counter_ty,
+                Scalar::from_uint(value, size),
+                self.increment_span,
+            )
+        };
+
+        let mut copies = Vec::new();
+        for iteration in 0..=trip_count {
+            let before = value(self.start + iteration as u128);
+            let after = value(self.start + iteration as u128 + 1);
+            for &bb in &self.blocks {
+                if iteration == trip_count && bb != self.header {
+                    break;
+                }
+                let mut data = body.basic_blocks[bb].clone();
+
+                let phase = phases[&bb];
+                let mut replacer = ReplaceCounter { tcx, counter: self.counter, value: None };
+                for (statement_index, statement) in data.statements.iter_mut().enumerate() {
+                    let location = Location { block: bb, statement_index };
+                    replacer.value = match phase {
+                        Phase::BeforeIncrement => Some(before.clone()),
+                        Phase::AfterIncrement => Some(after.clone()),
+                        Phase::Increment if location < self.increment => Some(before.clone()),
+                        Phase::Increment if location == self.increment => {
+                            statement.kind = StatementKind::Assign(Box::new((
+                                self.counter.into(),
+                                Rvalue::Use(after.clone()),
+                            )));
+                            continue;
+                        }
+                        Phase::Increment => Some(after.clone()),
+                        Phase::Unknown => None,
+                    };
+                    replacer.visit_statement(statement, location);
+                }
+                replacer.value = match phase {
+                    Phase::BeforeIncrement => Some(before.clone()),
+                    Phase::AfterIncrement | Phase::Increment => Some(after.clone()),
+                    Phase::Unknown => None,
+                };
+                let location = Location { block: bb, statement_index: data.statements.len() };
+                replacer.visit_terminator(data.terminator_mut(), location);
+
+                if bb == self.header {
+                    let target = if iteration == trip_count {
+                        self.exit
+                    } else {
+                        copy(iteration, self.body_target)
+                    };
+                    data.terminator_mut().kind = TerminatorKind::Goto { target };
+                } else {
+                    for target in data.terminator_mut().successors_mut() {
+                        if *target == self.header {
+                            *target = copy(iteration + 1, self.header);
+                        } else if position.contains_key(target) {
+                            *target = copy(iteration, *target);
+                        }
+                    }
+                }
+                copies.push(data);
+            }
+        }
+
+        let basic_blocks = body.basic_blocks_mut();
+        basic_blocks.extend(copies);
+        let TerminatorKind::Goto { ref mut target } =
+            basic_blocks[self.preheader].terminator_mut().kind
+        else {
+            bug!()
+        };
+        *target = copy(0, self.header);
+    }
+}
+
+/// The index and value of the last assignment to `local` in `statements`.
+fn last_assignment<'a, 'tcx>(
+    statements: &'a [Statement<'tcx>],
+    local: Local,
+) -> Option<(usize, &'a Rvalue<'tcx>)> {
+    statements.iter().enumerate().rev().find_map(|(index, statement)| match statement.kind {
+        StatementKind::Assign(box (place, ref rvalue)) if place.as_local() == Some(local) => {
+            Some((index, rvalue))
+        }
+        _ => None,
+    })
+}
+
+/// The locations in `bb` where `counter` may be modified, in order.
+fn modifications(body: &Body<'_>, counter: Local, bb: BasicBlock) -> Vec<Location> {
+    struct Modifications {
+        counter: Local,
+        locations: Vec<Location>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for Modifications {
+        fn visit_local(&mut self, local: Local, context: PlaceContext, location: Location) {
+            if local == self.counter && (context.is_mutating_use() || context.is_storage_marker()) {
+                self.locations.push(location);
+            }
+        }
+    }
+
+    let mut visitor = Modifications { counter, locations: Vec::new() };
+    visitor.visit_basic_block_data(bb, &body.basic_blocks[bb]);
+    visitor.locations
+}
+
+/// Replaces the reads of `counter` by `value`.
+struct ReplaceCounter<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    counter: Local,
+    value: Option<Operand<'tcx>>,
+}
+
+impl<'tcx> MutVisitor<'tcx> for ReplaceCounter<'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, location: Location) {
+        if let Some(value) = &self.value
+            && operand.place().and_then(|place| place.as_local()) == Some(self.counter)
+        {
+            *operand = value.clone();
+        }
+        self.super_operand(operand, location);
+    }
+}
@@ -0,0 +1,91 @@
+//! Replaces the runtime checks of the body by checks that only report their location when they
+//! fail, with `-C panic-messages=minimal`.
+//!
+//! A failed `assert` terminator calls a lang item specific to its `AssertKind`, with the operands
+//! of the check, like the index and length of an out of bounds access, so that it can format a
+//! message. We turn the `assert` into a `switchInt` on its condition, and call `panic_minimal`
+//! instead. That function takes no arguments, and gets the location of the check through
+//! `#[track_caller]`.
+//!
+//! Overflow checks are left alone, since the operation that overflowed is all their message says.
+//! The pointer checks are left alone too: they must not unwind, while `panic_minimal` may.
+//!
+//! A `no_std` program with its own panic handler may have no other way to tell which check failed,
+//! so we only do this when the panic handler is the one of `std`.
+
+use rustc_hir::lang_items::LangItem;
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::config::{OptLevel, PanicMessages};
+use rustc_session::Session;
+use rustc_span::sym;
+use tracing::debug;
+
+pub(super) struct PanicHandlerPass;
+
+impl<'tcx> crate::MirPass<'tcx> for PanicHandlerPass {
+    fn is_enabled(&self, sess: &Session) -> bool {
+        sess.opts.cg.panic_messages == PanicMessages::Minimal && sess.opts.optimize != OptLevel::No
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let Some(panic_minimal) = tcx.lang_items().get(LangItem::PanicMinimal) else { return };
+        match tcx.lang_items().panic_impl() {
+            Some(panic_impl) if tcx.crate_name(panic_impl.krate) == sym::std => {}
+            _ => return,
+        }
+
+        let func = Operand::function_handle(tcx, panic_minimal, [], body.span);
+        let never = body.local_decls.push(LocalDecl::new(tcx.types.never, body.span));
+
+        let basic_blocks = body.basic_blocks.as_mut();
+        let first_panic = basic_blocks.len();
+        let mut panics = Vec::new();
+        for (bb, data) in basic_blocks.iter_enumerated_mut() {
+            let is_cleanup = data.is_cleanup;
+            let terminator = data.terminator_mut();
+            let TerminatorKind::Assert { ref cond, expected, target, ref msg, unwind } =
+                terminator.kind
+            else {
+                continue;
+            };
+            if matches!(
+                **msg,
+                AssertKind::Overflow(..)
+                    | AssertKind::OverflowNeg(_)
+                    | AssertKind::MisalignedPointerDereference { .. }
+                    | AssertKind::NullPointerDereference
+            ) {
+                continue;
+            }
+            debug!(?bb, ?msg, "stripping message");
+
+            let cond = cond.clone();
+            let source_info = terminator.source_info;
+            let panic = BasicBlock::from_usize(first_panic + panics.len());
+            let targets = if expected {
+                SwitchTargets::static_if(0, panic, target)
+            } else {
+                SwitchTargets::static_if(0, target, panic)
+            };
+            terminator.kind = TerminatorKind::SwitchInt { discr: cond, targets };
+            panics.push(BasicBlockData {
+                statements: vec![],
+                terminator: Some(Terminator {
+                    source_info,
+                    kind: TerminatorKind::Call {
+                        func: func.clone(),
+                        args: Box::new([]),
+                        destination: never.into(),
+                        target: None,
+                        unwind,
+                        call_source: CallSource::Misc,
+                        fn_span: source_info.span,
+                    },
+                }),
+                is_cleanup,
+            });
+        }
+        basic_blocks.extend(panics);
+    }
+}
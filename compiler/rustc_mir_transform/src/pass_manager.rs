@@ -1,14 +1,23 @@
 use std::cell::RefCell;
 use std::collections::hash_map::Entry;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, Once};
+use std::time::{Duration, Instant};
+use std::{panic, thread};
 
 use rustc_data_structures::fx::FxHashMap;
 use rustc_middle::mir::{self, Body, MirPhase, RuntimePhase};
 use rustc_middle::ty::TyCtxt;
-use rustc_session::Session;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_session::code_stats::MirKindCounts;
+use rustc_session::config::{ErrorOutputType, MirStats};
+use rustc_session::{EarlyDiagCtxt, Session};
+use rustc_span::def_id::DefId;
+use rustc_span::fatal_error::FatalErrorMarker;
 use tracing::trace;
 
 use crate::lint::lint_body;
-use crate::validate;
+use crate::{errors, validate};
 
 thread_local! {
     static PASS_NAMES: RefCell<FxHashMap<&'static str, &'static str>> = {
@@ -203,6 +212,10 @@ fn run_passes_inner<'tcx>(
     if !body.should_skip() {
         let validate = validate_each & tcx.sess.opts.unstable_opts.validate_mir;
         let lint = tcx.sess.opts.unstable_opts.lint_mir;
+        let time_passes = tcx.sess.opts.unstable_opts.time_passes;
+        let timeout = tcx.sess.opts.unstable_opts.mir_pass_timeout.map(Duration::from_secs);
+        let def_id = body.source.def_id();
+        let watched_body = timeout.map(|_| tcx.def_path_str(def_id));
 
         for pass in passes {
             let name = pass.name();
@@ -217,24 +230,51 @@ fn run_passes_inner<'tcx>(
                 dump_mir_for_pass(tcx, body, name, false);
             }
 
-            if let Some(prof_arg) = &prof_arg {
-                tcx.sess
-                    .prof
-                    .generic_activity_with_arg(pass.profiler_name(), &**prof_arg)
-                    .run(|| pass.run_pass(tcx, body));
-            } else {
-                pass.run_pass(tcx, body);
+            let start = (time_passes || timeout.is_some()).then(Instant::now);
+            let watched_pass = timeout.zip(watched_body.as_deref()).map(|(timeout, body_name)| {
+                WATCHDOG.watch(name, body_name, timeout, tcx.sess.opts.error_format)
+            });
+            note_panics(tcx, def_id, name, false, || {
+                if let Some(prof_arg) = &prof_arg {
+                    tcx.sess
+                        .prof
+                        .generic_activity_with_arg(pass.profiler_name(), &**prof_arg)
+                        .run(|| pass.run_pass(tcx, body));
+                } else {
+                    pass.run_pass(tcx, body);
+                }
+            });
+            drop(watched_pass);
+            if let Some(start) = start {
+                let elapsed = start.elapsed();
+                if time_passes {
+                    tcx.sess.code_stats.record_mir_pass_time(pass.profiler_name(), elapsed);
+                }
+                // The watchdog has already warned about this pass if it still was running at the
+                // timeout, this also gives the total time and points at the body.
+                if let Some(timeout) = timeout
+                    && elapsed >= timeout
+                {
+                    tcx.dcx().emit_warn(errors::SlowMirPass {
+                        span: body.span,
+                        pass: name,
+                        elapsed: format!("{:.2}", elapsed.as_secs_f64()),
+                        timeout: timeout.as_secs(),
+                    });
+                }
             }
 
             if dump_enabled {
                 dump_mir_for_pass(tcx, body, name, true);
             }
-            if validate {
-                validate_body(tcx, body, format!("after pass {name}"));
-            }
-            if lint {
-                lint_body(tcx, body, format!("after pass {name}"));
-            }
+            note_panics(tcx, def_id, name, true, || {
+                if validate {
+                    validate_body(tcx, body, format!("after pass {name}"));
+                }
+                if lint {
+                    lint_body(tcx, body, format!("after pass {name}"));
+                }
+            });
 
             body.pass_count += 1;
         }
@@ -262,7 +302,51 @@ fn run_passes_inner<'tcx>(
         }
 
         body.pass_count = 1;
+
+        if let Some(mir_stats) = tcx.sess.opts.unstable_opts.mir_stats {
+            record_mir_stats(tcx, body, mir_stats);
+        }
+    }
+}
+
+/// Counts the kinds of statements and terminators of `body` for `-Z mir-stats`, under the phase
+/// the body just reached. Only done on phase changes so that each body is counted once per phase.
+fn record_mir_stats<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, mir_stats: MirStats) {
+    let mut counts = MirKindCounts::default();
+    for data in body.basic_blocks.iter() {
+        for statement in &data.statements {
+            *counts.statements.entry(statement.kind.name()).or_default() += 1;
+        }
+        *counts.terminators.entry(data.terminator().kind.name()).or_default() += 1;
     }
+
+    let body_name = (mir_stats == MirStats::Verbose).then(|| {
+        let def_path = tcx.def_path_str(body.source.def_id());
+        match body.source.promoted {
+            Some(promoted) => format!("{def_path}::{promoted:?}"),
+            None => def_path,
+        }
+    });
+    tcx.sess.code_stats.record_mir_stats(body.phase.name(), body_name, counts);
+}
+
+/// Runs `f` for `pass`, or for the checks after `pass` if `checking`, and notes which pass was
+/// running on which body if it ICEs. The query stack only tells which body was being optimized.
+fn note_panics(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    pass: &'static str,
+    checking: bool,
+    f: impl FnOnce(),
+) {
+    panic::catch_unwind(panic::AssertUnwindSafe(f)).unwrap_or_else(|value| {
+        // Regular fatal errors are not worth a note.
+        if !value.is::<FatalErrorMarker>() {
+            let body = with_no_trimmed_paths!(tcx.def_path_str(def_id));
+            tcx.dcx().emit_note(errors::MirPassPanicked { pass, body, checking });
+        }
+        panic::resume_unwind(value)
+    })
 }
 
 pub(super) fn validate_body<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, when: String) {
@@ -284,3 +368,101 @@ pub(super) fn dump_mir_for_phase_change<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tc
     assert_eq!(body.pass_count, 0);
     mir::dump_mir(tcx, true, body.phase.name(), &"after", body, |_, _| Ok(()))
 }
+
+/// Warns about the passes that are still running on a body after `-Zmir-pass-timeout`, while they
+/// are running. This is done by a single thread, started by the first pass that runs with the
+/// option and kept until the process exits, so running a pass only registers it in `running`.
+struct PassWatchdog {
+    running: Mutex<Vec<RunningPass>>,
+    changed: Condvar,
+    started: Once,
+    next_id: AtomicUsize,
+}
+
+struct RunningPass {
+    id: usize,
+    pass: &'static str,
+    body: String,
+    start: Instant,
+    deadline: Instant,
+    reported: bool,
+}
+
+static WATCHDOG: PassWatchdog = PassWatchdog {
+    running: Mutex::new(Vec::new()),
+    changed: Condvar::new(),
+    started: Once::new(),
+    next_id: AtomicUsize::new(0),
+};
+
+impl PassWatchdog {
+    fn watch(
+        &'static self,
+        pass: &'static str,
+        body: &str,
+        timeout: Duration,
+        error_format: ErrorOutputType,
+    ) -> WatchedPass {
+        self.started.call_once(|| {
+            thread::Builder::new()
+                .name("mir-pass-watchdog".to_owned())
+                .spawn(move || self.run(error_format))
+                .expect("failed to spawn the MIR pass watchdog");
+        });
+
+        let start = Instant::now();
+        let deadline = start + timeout;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut running = self.running.lock().unwrap();
+        // The thread only has to be woken up if it is not already waiting for an earlier deadline.
+        let wake_up =
+            running.iter().filter(|pass| !pass.reported).all(|pass| pass.deadline > deadline);
+        running.push(RunningPass {
+            id,
+            pass,
+            body: body.to_owned(),
+            start,
+            deadline,
+            reported: false,
+        });
+        if wake_up {
+            self.changed.notify_one();
+        }
+        WatchedPass { id }
+    }
+
+    fn run(&self, error_format: ErrorOutputType) {
+        let dcx = EarlyDiagCtxt::new(error_format);
+        let mut running = self.running.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            for pass in running.iter_mut().filter(|pass| !pass.reported && pass.deadline <= now) {
+                dcx.early_warn(format!(
+                    "MIR pass `{}` has been running on `{}` for {:.2}s",
+                    pass.pass,
+                    pass.body,
+                    (now - pass.start).as_secs_f64(),
+                ));
+                pass.reported = true;
+            }
+
+            let next_deadline =
+                running.iter().filter(|pass| !pass.reported).map(|pass| pass.deadline).min();
+            running = match next_deadline {
+                Some(deadline) => self.changed.wait_timeout(running, deadline - now).unwrap().0,
+                None => self.changed.wait(running).unwrap(),
+            };
+        }
+    }
+}
+
+/// Unregisters a pass from the watchdog once it has returned or unwound.
+struct WatchedPass {
+    id: usize,
+}
+
+impl Drop for WatchedPass {
+    fn drop(&mut self) {
+        WATCHDOG.running.lock().unwrap().retain(|pass| pass.id != self.id);
+    }
+}
@@ -1,3 +1,5 @@
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir::visit::{PlaceContext, Visitor};
 use rustc_middle::mir::*;
 use rustc_middle::ty::TyCtxt;
 use tracing::trace;
@@ -7,7 +9,9 @@ pub(super) enum SimplifyConstCondition {
     Final,
 }
 
-/// A pass that replaces a branch with a goto when its condition is known.
+/// A pass that replaces a branch with a goto when its condition is known, either because it is a
+/// constant, or because it is a local that was assigned a constant earlier in the same block,
+/// directly or through copies of other such locals.
 impl<'tcx> crate::MirPass<'tcx> for SimplifyConstCondition {
     fn name(&self) -> &'static str {
         match self {
@@ -19,8 +23,11 @@ fn name(&self) -> &'static str {
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         trace!("Running SimplifyConstCondition on {:?}", body.source);
         let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
-        'blocks: for block in body.basic_blocks_mut() {
-            for stmt in block.statements.iter_mut() {
+        'blocks: for (bb, block) in body.basic_blocks_mut().iter_enumerated_mut() {
+            let mut known = KnownConstants::default();
+            for (statement_index, stmt) in block.statements.iter_mut().enumerate() {
+                known.visit_statement(stmt, Location { block: bb, statement_index });
+
                 // Simplify `assume` of a known value: either a NOP or unreachable.
                 if let StatementKind::Intrinsic(box ref intrinsic) = stmt.kind
                     && let NonDivergingIntrinsic::Assume(discr) = intrinsic
@@ -39,9 +46,16 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
 
             let terminator = block.terminator_mut();
             terminator.kind = match terminator.kind {
-                TerminatorKind::SwitchInt {
-                    discr: Operand::Constant(ref c), ref targets, ..
-                } => {
+                TerminatorKind::SwitchInt { ref discr, ref targets, .. } => {
+                    let c = match discr {
+                        Operand::Constant(c) => &**c,
+                        Operand::Copy(place) | Operand::Move(place) => {
+                            match place.as_local().and_then(|local| known.locals.get(&local)) {
+                                Some(c) => c,
+                                None => continue,
+                            }
+                        }
+                    };
                     let constant = c.const_.try_eval_bits(tcx, param_env);
                     if let Some(constant) = constant {
                         let target = targets.target_for_value(constant);
@@ -61,3 +75,55 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         }
     }
 }
+
+/// Locals that were assigned a constant, or a copy of such a local, earlier in the current block,
+/// and that have not been modified since.
+#[derive(Default)]
+struct KnownConstants<'tcx> {
+    locals: FxHashMap<Local, ConstOperand<'tcx>>,
+}
+
+impl<'tcx> KnownConstants<'tcx> {
+    fn known_operand(&self, operand: &Operand<'tcx>) -> Option<ConstOperand<'tcx>> {
+        match operand {
+            Operand::Constant(c) => Some((**c).clone()),
+            Operand::Copy(place) | Operand::Move(place) => {
+                self.locals.get(&place.as_local()?).cloned()
+            }
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for KnownConstants<'tcx> {
+    fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
+        match &statement.kind {
+            StatementKind::Assign(box (place, Rvalue::Use(operand)))
+                if let Some(local) = place.as_local()
+                    && let Some(c) = self.known_operand(operand) =>
+            {
+                self.locals.insert(local, c);
+            }
+            // This may write through any pointer.
+            StatementKind::Intrinsic(box NonDivergingIntrinsic::CopyNonOverlapping(..)) => {
+                self.locals.clear();
+            }
+            _ => self.super_statement(statement, location),
+        }
+    }
+
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _: Location) {
+        if !(context.is_mutating_use()
+            || context.is_borrow()
+            || context.is_address_of()
+            || context.is_storage_marker())
+        {
+            return;
+        }
+        if place.is_indirect() {
+            // We do not know which local this writes to.
+            self.locals.clear();
+        } else {
+            self.locals.remove(&place.local);
+        }
+    }
+}
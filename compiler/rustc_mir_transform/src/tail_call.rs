@@ -0,0 +1,155 @@
+//! Turns self-recursive tail calls into jumps back to the start of the function.
+//!
+//! A call `_0 = f(args) -> bbN`, where `f` is the function being compiled with its own generic
+//! arguments, and where `bbN` only ends the storage of some locals before returning, can reuse
+//! the current frame: we assign the arguments to the parameters and jump back to the code of the
+//! start block instead. The recursion depth then no longer depends on the inputs, and deep
+//! recursions cannot overflow the stack. As this also removes the frames of the recursive calls
+//! from backtraces, the pass only runs with `-Zmir-tail-call-opt`.
+//!
+//! This runs after drop elaboration, so a local that still needs to be dropped after the call
+//! shows up as a `Drop` terminator between the call and the `return`, and such calls are left
+//! alone. The transformation is also only correct if no local of the frame has its address taken.
+//! Otherwise, the call may receive a pointer to one of our locals, and that local would be
+//! overwritten when the body executes again.
+
+use rustc_hir::def::DefKind;
+use rustc_middle::bug;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{GenericArgs, TyCtxt};
+use rustc_mir_dataflow::impls::borrowed_locals;
+use tracing::{debug, instrument};
+
+pub(super) struct TailCallOptimization;
+
+impl<'tcx> crate::MirPass<'tcx> for TailCallOptimization {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.opts.unstable_opts.mir_tail_call_opt
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let def_id = body.source.def_id();
+        debug!(?def_id);
+
+        // Only plain functions have a `FnDef` type that we can recognize in calls. Calls to
+        // `#[track_caller]` functions take the location as an implicit argument, and `rust-call`
+        // and C-variadic functions do not take their arguments one by one.
+        if body.source.promoted.is_some()
+            || body.coroutine.is_some()
+            || body.spread_arg.is_some()
+            || !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || tcx.fn_sig(def_id).skip_binder().c_variadic()
+            || tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+        {
+            return;
+        }
+        if !borrowed_locals(body).is_empty() {
+            return;
+        }
+
+        let identity_args = tcx.erase_regions(GenericArgs::identity_for_item(tcx, def_id));
+        let tail_calls: Vec<_> = body
+            .basic_blocks
+            .iter_enumerated()
+            .filter_map(|(bb, data)| {
+                let TerminatorKind::Call { ref func, ref args, destination, target, .. } =
+                    data.terminator().kind
+                else {
+                    return None;
+                };
+                if data.is_cleanup
+                    || destination != Place::return_place()
+                    || args.len() != body.arg_count
+                    || func.const_fn_def() != Some((def_id, identity_args))
+                {
+                    return None;
+                }
+
+                let path = return_path(body, target?)?;
+                Some((bb, path))
+            })
+            .collect();
+
+        if tail_calls.is_empty() {
+            return;
+        }
+
+        // The start block must not have predecessors, so we move its contents to a new block that
+        // the tail calls can jump to, and only leave a `goto` to it in the start block.
+        let source_info = SourceInfo::outermost(body.span);
+        let basic_blocks = body.basic_blocks_mut();
+        let entry = basic_blocks.next_index();
+        let goto_entry = BasicBlockData::new(Some(Terminator {
+            source_info,
+            kind: TerminatorKind::Goto { target: entry },
+        }));
+        let start = std::mem::replace(&mut basic_blocks[START_BLOCK], goto_entry);
+        basic_blocks.push(start);
+
+        for (bb, return_path) in tail_calls {
+            let bb = if bb == START_BLOCK { entry } else { bb };
+            debug!(?bb, "replacing tail call");
+            let terminator = body.basic_blocks[bb].terminator();
+            let source_info = terminator.source_info;
+            let TerminatorKind::Call { ref args, .. } = terminator.kind else { bug!() };
+            let args: Vec<_> = args.iter().map(|arg| arg.node.clone()).collect();
+
+            // The arguments may read the parameters, so evaluate all of them before overwriting
+            // any parameter.
+            let mut statements = Vec::with_capacity(2 * args.len());
+            let mut temps = Vec::with_capacity(args.len());
+            for arg in args {
+                let ty = arg.ty(&body.local_decls, tcx);
+                let temp = body.local_decls.push(LocalDecl::new(ty, source_info.span));
+                statements.push(Statement {
+                    source_info,
+                    kind: StatementKind::Assign(Box::new((temp.into(), Rvalue::Use(arg)))),
+                });
+                temps.push(temp);
+            }
+            // End the storage of the locals like the return path did. Locals that are still live
+            // at this point are reset by their `StorageLive` when the body executes again.
+            for &block in &return_path {
+                statements.extend(body.basic_blocks[block].statements.iter().cloned());
+            }
+            for (param, temp) in body.args_iter().zip(temps) {
+                statements.push(Statement {
+                    source_info,
+                    kind: StatementKind::Assign(Box::new((
+                        param.into(),
+                        Rvalue::Use(Operand::Move(temp.into())),
+                    ))),
+                });
+            }
+
+            let data = &mut body.basic_blocks_mut()[bb];
+            data.statements.extend(statements);
+            data.terminator_mut().kind = TerminatorKind::Goto { target: entry };
+        }
+    }
+}
+
+/// Returns the blocks that lead from `target` to a `return` when those only end the storage of
+/// some locals and jump to the next one.
+fn return_path(body: &Body<'_>, mut target: BasicBlock) -> Option<Vec<BasicBlock>> {
+    let mut path = Vec::new();
+    // Bound the walk, in case the `goto`s form a cycle.
+    for _ in 0..body.basic_blocks.len() {
+        path.push(target);
+        let data = &body.basic_blocks[target];
+        let only_storage_dead = data.statements.iter().all(|statement| {
+            matches!(statement.kind, StatementKind::StorageDead(_) | StatementKind::Nop)
+        });
+        if data.is_cleanup || !only_storage_dead {
+            return None;
+        }
+        match data.terminator().kind {
+            TerminatorKind::Return => return Some(path),
+            TerminatorKind::Goto { target: next } => target = next,
+            _ => return None,
+        }
+    }
+    None
+}
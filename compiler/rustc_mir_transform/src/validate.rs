@@ -14,6 +14,7 @@
     Variance,
 };
 use rustc_middle::{bug, span_bug};
+use rustc_span::Span;
 use rustc_target::abi::{Size, FIRST_VARIANT};
 use rustc_target::spec::abi::Abi;
 
@@ -122,14 +123,16 @@ impl<'a, 'tcx> CfgChecker<'a, 'tcx> {
     #[track_caller]
     fn fail(&self, location: Location, msg: impl AsRef<str>) {
         // We might see broken MIR when other errors have already occurred.
-        assert!(
-            self.tcx.dcx().has_errors().is_some(),
-            "broken MIR in {:?} ({}) at {:?}:\n{}",
-            self.body.source.instance,
-            self.when,
-            location,
-            msg.as_ref(),
-        );
+        if self.tcx.dcx().has_errors().is_none() {
+            span_bug!(
+                self.body.source_info(location).span,
+                "broken MIR in {:?} ({}) at {:?}:\n{}",
+                self.body.source.instance,
+                self.when,
+                location,
+                msg.as_ref(),
+            );
+        }
     }
 
     fn check_edge(&mut self, location: Location, bb: BasicBlock, edge_kind: EdgeKind) {
@@ -526,6 +529,35 @@ fn visit_source_scope(&mut self, scope: SourceScope) {
     }
 }
 
+/// A type inconsistency found by [`validate_mir`].
+#[derive(Debug)]
+pub struct MirError {
+    pub location: Location,
+    pub span: Span,
+    pub message: String,
+}
+
+/// Runs the type checks of the validator on `body`, and returns the inconsistencies it found
+/// instead of reporting them as ICEs.
+///
+/// Made public so that custom rustc drivers and other tools that synthesize MIR can check their
+/// bodies before handing them to the rest of the compiler.
+pub fn validate_mir<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Vec<MirError> {
+    let def_id = body.source.def_id();
+    let param_env = match body.phase.reveal() {
+        Reveal::UserFacing => tcx.param_env(def_id),
+        Reveal::All => tcx.param_env_reveal_all_normalized(def_id),
+    };
+    validate_types(tcx, body.phase, param_env, body, body)
+        .into_iter()
+        .map(|(location, message)| MirError {
+            location,
+            span: body.source_info(location).span,
+            message,
+        })
+        .collect()
+}
+
 /// A faster version of the validation pass that only checks those things which may break when
 /// instantiating any generic parameters.
 ///
@@ -1506,6 +1538,34 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     // FIXME(explicit_tail_calls): implement tail-call specific checks here (such
                     // as signature matching, forbidding closures, etc)
                 }
+
+                // A call that never returns may have a destination of any type.
+                if let TerminatorKind::Call { destination, target: Some(_), .. } = terminator.kind
+                    && let ty::FnPtr(..) | ty::FnDef(..) = func_ty.kind()
+                {
+                    let sig = func_ty.fn_sig(self.tcx);
+                    let output = self.tcx.instantiate_bound_regions_with_erased(sig.output());
+                    let dest_ty = destination.ty(&self.body.local_decls, self.tcx).ty;
+                    // Call destinations are not given `Subtype` projections, so they may still
+                    // be supertypes of the return type after borrowck.
+                    if !output.has_opaque_types()
+                        && !dest_ty.has_opaque_types()
+                        && !relate_types(
+                            self.tcx,
+                            self.param_env,
+                            Variance::Covariant,
+                            output,
+                            dest_ty,
+                        )
+                    {
+                        self.fail(
+                            location,
+                            format!(
+                                "encountered call returning {output} into destination of type {dest_ty}"
+                            ),
+                        );
+                    }
+                }
             }
             TerminatorKind::Assert { cond, .. } => {
                 let cond_ty = cond.ty(&self.body.local_decls, self.tcx);
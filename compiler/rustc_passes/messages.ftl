@@ -287,6 +287,12 @@ passes_duplicate_lang_item_crate_depends =
 passes_empty_confusables =
     expected at least one confusable name
 
+passes_estimated_size =
+    estimated size: {$size}
+
+passes_estimated_size_invalid_attribute =
+    `#[rustc_dump_estimated_size]` can only be applied to functions
+
 passes_export_name =
     attribute should be applied to a free function, impl method or static
     .label = not a free function, impl method or static
@@ -992,6 +992,21 @@ pub(crate) struct AbiInvalidAttribute {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_estimated_size)]
+pub(crate) struct EstimatedSize {
+    #[primary_span]
+    pub span: Span,
+    pub size: usize,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_estimated_size_invalid_attribute)]
+pub(crate) struct EstimatedSizeInvalidAttribute {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_unrecognized_field)]
 pub(crate) struct UnrecognizedField {
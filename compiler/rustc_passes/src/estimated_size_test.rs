@@ -0,0 +1,25 @@
+use rustc_hir::def::DefKind;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::symbol::sym;
+
+use crate::errors::{EstimatedSize, EstimatedSizeInvalidAttribute};
+
+pub fn test_estimated_size(tcx: TyCtxt<'_>) {
+    if !tcx.features().rustc_attrs {
+        // if the `rustc_attrs` feature is not enabled, don't bother testing the estimated size
+        return;
+    }
+    for id in tcx.hir_crate_items(()).definitions() {
+        if tcx.has_attr(id, sym::rustc_dump_estimated_size) {
+            match tcx.def_kind(id) {
+                DefKind::Fn | DefKind::AssocFn => {
+                    let size = tcx.mir_estimated_size(id);
+                    tcx.dcx().emit_err(EstimatedSize { span: tcx.def_span(id), size });
+                }
+                _ => {
+                    tcx.dcx().emit_err(EstimatedSizeInvalidAttribute { span: tcx.def_span(id) });
+                }
+            }
+        }
+    }
+}
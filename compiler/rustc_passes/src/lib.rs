@@ -25,6 +25,7 @@
 mod diagnostic_items;
 pub mod entry;
 mod errors;
+pub mod estimated_size_test;
 #[cfg(debug_assertions)]
 pub mod hir_id_validator;
 pub mod hir_stats;
@@ -1,6 +1,8 @@
 use std::cmp;
+use std::time::Duration;
 
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_data_structures::profiling::{print_time_passes_entry, TimePassesFormat};
 use rustc_data_structures::sync::Lock;
 use rustc_span::def_id::DefId;
 use rustc_span::Symbol;
@@ -90,10 +92,42 @@ pub struct VTableSizeInfo {
     pub upcasting_cost_percent: f64,
 }
 
+/// How many statements and terminators of each kind a set of MIR bodies contains.
+#[derive(Default)]
+pub struct MirKindCounts {
+    pub statements: FxHashMap<&'static str, usize>,
+    pub terminators: FxHashMap<&'static str, usize>,
+}
+
+impl MirKindCounts {
+    fn add(&mut self, other: &MirKindCounts) {
+        #[allow(rustc::potential_query_instability)]
+        for (&kind, &count) in &other.statements {
+            *self.statements.entry(kind).or_default() += count;
+        }
+        #[allow(rustc::potential_query_instability)]
+        for (&kind, &count) in &other.terminators {
+            *self.terminators.entry(kind).or_default() += count;
+        }
+    }
+}
+
+#[derive(Default)]
+struct MirPhaseStats {
+    total: MirKindCounts,
+    /// Only recorded with `-Z mir-stats=verbose`.
+    bodies: Vec<(String, MirKindCounts)>,
+}
+
 #[derive(Default)]
 pub struct CodeStats {
     type_sizes: Lock<FxHashSet<TypeSizeInfo>>,
     vtable_sizes: Lock<FxHashMap<DefId, VTableSizeInfo>>,
+    /// Total wall-clock time spent in each MIR pass, over all bodies.
+    mir_pass_times: Lock<FxHashMap<&'static str, Duration>>,
+    /// The kinds of MIR statements and terminators, by the MIR phase the bodies were in, in the
+    /// order in which the phases were first reached.
+    mir_stats: Lock<FxIndexMap<&'static str, MirPhaseStats>>,
 }
 
 impl CodeStats {
@@ -135,6 +169,26 @@ pub fn record_vtable_size(&self, trait_did: DefId, trait_name: &str, info: VTabl
         );
     }
 
+    pub fn record_mir_pass_time(&self, pass_name: &'static str, duration: Duration) {
+        *self.mir_pass_times.lock().entry(pass_name).or_default() += duration;
+    }
+
+    /// Records the kinds of the statements and terminators of a body that just reached `phase`.
+    /// `body_name` is given when the counts of this body should be printed on their own.
+    pub fn record_mir_stats(
+        &self,
+        phase: &'static str,
+        body_name: Option<String>,
+        counts: MirKindCounts,
+    ) {
+        let mut mir_stats = self.mir_stats.lock();
+        let stats = mir_stats.entry(phase).or_default();
+        stats.total.add(&counts);
+        if let Some(body_name) = body_name {
+            stats.bodies.push((body_name, counts));
+        }
+    }
+
     pub fn print_type_sizes(&self) {
         let type_sizes = self.type_sizes.borrow();
         // We will soon sort, so the initial order does not matter.
@@ -267,4 +321,46 @@ pub fn print_vtable_sizes(&self, crate_name: Symbol) {
             );
         }
     }
+
+    pub fn print_mir_pass_times(&self, format: TimePassesFormat) {
+        // We will soon sort, so the initial order does not matter.
+        #[allow(rustc::potential_query_instability)]
+        let mut times =
+            std::mem::take(&mut *self.mir_pass_times.lock()).into_iter().collect::<Vec<_>>();
+
+        // Primary sort: total time in reverse order (from slowest to fastest)
+        // Secondary sort: pass name
+        times.sort_by_key(|&(pass_name, duration)| (cmp::Reverse(duration), pass_name));
+
+        for (pass_name, duration) in times {
+            print_time_passes_entry(pass_name, duration, None, None, format);
+        }
+    }
+
+    pub fn print_mir_stats(&self) {
+        fn print_counts(indent: &str, what: &str, counts: &FxHashMap<&'static str, usize>) {
+            // We will soon sort, so the initial order does not matter.
+            #[allow(rustc::potential_query_instability)]
+            let mut counts: Vec<_> = counts.iter().map(|(&kind, &count)| (kind, count)).collect();
+            // Most frequent kinds first, then by name.
+            counts.sort_by_key(|&(kind, count)| (cmp::Reverse(count), kind));
+
+            let total: usize = counts.iter().map(|&(_, count)| count).sum();
+            eprintln!("{indent}{what}: {total}");
+            for (kind, count) in counts {
+                eprintln!("{indent}    {kind:<24} {count:>10}");
+            }
+        }
+
+        for (phase, stats) in std::mem::take(&mut *self.mir_stats.lock()) {
+            eprintln!("mir-stats after phase `{phase}`:");
+            print_counts("    ", "statements", &stats.total.statements);
+            print_counts("    ", "terminators", &stats.total.terminators);
+            for (body_name, counts) in stats.bodies {
+                eprintln!("    {body_name}:");
+                print_counts("        ", "statements", &counts.statements);
+                print_counts("        ", "terminators", &counts.terminators);
+            }
+        }
+    }
 }
@@ -421,6 +421,16 @@ impl FmtDebug {
     }
 }
 
+/// Values for the `-C panic-messages` flag.
+#[derive(Copy, Clone, PartialEq, Hash, Debug)]
+pub enum PanicMessages {
+    /// Failed runtime checks report what went wrong, like the index and length of an out of bounds
+    /// access.
+    Full,
+    /// Failed runtime checks, other than overflow checks, only report their location.
+    Minimal,
+}
+
 #[derive(Clone, PartialEq, Hash, Debug)]
 pub enum SwitchWithOptPath {
     Enabled(Option<PathBuf>),
@@ -2514,6 +2524,10 @@ pub fn build_session_options(early_dcx: &mut EarlyDiagCtxt, matches: &getopts::M
         }
     }
 
+    if cg.panic_messages == PanicMessages::Minimal && !unstable_opts.unstable_options {
+        early_dcx.early_fatal("`-C panic-messages=minimal` requires `-Z unstable-options`");
+    }
+
     if cg.instrument_coverage != InstrumentCoverage::No {
         if cg.profile_generate.enabled() || cg.profile_use.is_some() {
             early_dcx.early_fatal(
@@ -3016,7 +3030,7 @@ pub(crate) mod dep_tracking {
         CrateType, DebugInfo, DebugInfoCompression, ErrorOutputType, FmtDebug, FunctionReturn,
         InliningThreshold, InstrumentCoverage, InstrumentXRay, LinkerPluginLto, LocationDetail,
         LtoCli, NextSolverConfig, OomStrategy, OptLevel, OutFileName, OutputType, OutputTypes,
-        PatchableFunctionEntry, Polonius, RemapPathScopeComponents, ResolveDocLinks,
+        PanicMessages, PatchableFunctionEntry, Polonius, RemapPathScopeComponents, ResolveDocLinks,
         SourceFileHashAlgorithm, SplitDwarfKind, SwitchWithOptPath, SymbolManglingVersion,
         WasiExecModel,
     };
@@ -3108,6 +3122,7 @@ fn hash(
         RealFileName,
         LocationDetail,
         FmtDebug,
+        PanicMessages,
         BranchProtection,
         OomStrategy,
         LanguageIdentifier,
@@ -3391,3 +3406,12 @@ pub fn is_enabled(self) -> bool {
         self == MirIncludeSpans::On
     }
 }
+
+/// The level of detail of the report printed by `-Z mir-stats`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MirStats {
+    /// Counts summed over all the bodies of the crate.
+    Summary,
+    /// The summary, followed by the counts of each body.
+    Verbose,
+}
@@ -410,6 +410,7 @@ mod desc {
         "either a boolean (`yes`, `no`, `on`, `off`, etc), or the path to the linker plugin";
     pub(crate) const parse_location_detail: &str = "either `none`, or a comma separated list of location details to track: `file`, `line`, or `column`";
     pub(crate) const parse_fmt_debug: &str = "either `full`, `shallow`, or `none`";
+    pub(crate) const parse_panic_messages: &str = "either `full` or `minimal`";
     pub(crate) const parse_switch_with_opt_path: &str =
         "an optional path to the profiling data output directory";
     pub(crate) const parse_merge_functions: &str =
@@ -451,6 +452,7 @@ mod desc {
     pub(crate) const parse_wasm_c_abi: &str = "`legacy` or `spec`";
     pub(crate) const parse_mir_include_spans: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), or `nll` (default: `nll`)";
+    pub(crate) const parse_mir_stats: &str = "either no value or `verbose`";
 }
 
 mod parse {
@@ -606,6 +608,15 @@ pub(crate) fn parse_fmt_debug(opt: &mut FmtDebug, v: Option<&str>) -> bool {
         true
     }
 
+    pub(crate) fn parse_panic_messages(slot: &mut PanicMessages, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("full") => PanicMessages::Full,
+            Some("minimal") => PanicMessages::Minimal,
+            _ => return false,
+        };
+        true
+    }
+
     pub(crate) fn parse_location_detail(ld: &mut LocationDetail, v: Option<&str>) -> bool {
         if let Some(v) = v {
             ld.line = false;
@@ -1505,6 +1516,16 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
 
         true
     }
+
+    pub(crate) fn parse_mir_stats(slot: &mut Option<MirStats>, v: Option<&str>) -> bool {
+        *slot = match v {
+            None => Some(MirStats::Summary),
+            Some("verbose") => Some(MirStats::Verbose),
+            _ => return false,
+        };
+
+        true
+    }
 }
 
 options! {
@@ -1600,6 +1621,8 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
     #[rustc_lint_opt_deny_field_access("use `Session::panic_strategy` instead of this field")]
     panic: Option<PanicStrategy> = (None, parse_opt_panic_strategy, [TRACKED],
         "panic strategy to compile crate with"),
+    panic_messages: PanicMessages = (PanicMessages::Full, parse_panic_messages, [TRACKED],
+        "how much the panics of failed runtime checks report: `full` or `minimal` (default: full)"),
     passes: Vec<String> = (Vec::new(), parse_list, [TRACKED],
         "a list of extra LLVM passes to run (space separated)"),
     prefer_dynamic: bool = (false, parse_bool, [TRACKED],
@@ -1718,6 +1741,8 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
         "exclude the pass number when dumping MIR (used in tests) (default: no)"),
     dump_mir_graphviz: bool = (false, parse_bool, [UNTRACKED],
         "in addition to `.mir` files, create graphviz `.dot` files (default: no)"),
+    dump_mir_json: bool = (false, parse_bool, [UNTRACKED],
+        "in addition to `.mir` files, create machine-readable `.json` files (default: no)"),
     dump_mono_stats: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
         parse_switch_with_opt_path, [UNTRACKED],
         "output statistics about monomorphization collection"),
@@ -1798,6 +1823,8 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
         "enable LLVM inlining (default: yes)"),
     inline_mir: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "enable MIR inlining (default: no)"),
+    inline_mir_depth_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "how many levels of calls can be inlined into the body of an inlined callee (default: 5)"),
     inline_mir_forwarder_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
         "inlining threshold when the caller is a simple forwarding function (default: 30)"),
     inline_mir_hint_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
@@ -1877,6 +1904,13 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
     #[rustc_lint_opt_deny_field_access("use `Session::mir_opt_level` instead of this field")]
     mir_opt_level: Option<usize> = (None, parse_opt_number, [TRACKED],
         "MIR optimization level (0-4; default: 1 in non optimized builds and 2 in optimized builds)"),
+    mir_pass_timeout: Option<u64> = (None, parse_opt_number, [UNTRACKED],
+        "warn when a MIR pass runs on a single body for at least this many seconds"),
+    mir_stats: Option<MirStats> = (None, parse_mir_stats, [UNTRACKED],
+        "print how many MIR statements and terminators of each kind there are after each MIR \
+        phase, over all the bodies of the crate (`verbose` also prints the counts of each body)"),
+    mir_tail_call_opt: bool = (false, parse_bool, [TRACKED],
+        "turn self-recursive tail calls into jumps to the start of the function (default: no)"),
     move_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
         "the size at which the `large_assignments` lint starts to be emitted"),
     mutable_noalias: bool = (true, parse_bool, [TRACKED],
@@ -2133,6 +2167,8 @@ pub(crate) fn parse_mir_include_spans(slot: &mut MirIncludeSpans, v: Option<&str
         `hir-tree` (dump the raw HIR),
         `thir-tree`, `thir-flat`,
         `mir` (the MIR), or `mir-cfg` (graphviz formatted MIR)"),
+    unroll_loops_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the maximum trip count of the loops unrolled by the MIR `LoopUnrolling` pass (default: 8)"),
     unsound_mir_opts: bool = (false, parse_bool, [TRACKED],
         "enable unsound and buggy MIR optimizations (default: no)"),
     /// This name is kind of confusing: Most unstable options enable something themselves, while
@@ -479,6 +479,9 @@ fn stable(&self, tables: &mut Tables<'_>) -> Self::T {
                     found: found.stable(tables),
                 }
             }
+            AssertKind::NullPointerDereference => {
+                stable_mir::mir::AssertMessage::NullPointerDereference
+            }
         }
     }
 }
@@ -1395,8 +1395,10 @@
         panic_in_cleanup,
         panic_info,
         panic_location,
+        panic_minimal,
         panic_misaligned_pointer_dereference,
         panic_nounwind,
+        panic_null_pointer_dereference,
         panic_runtime,
         panic_str_2015,
         panic_unwind,
@@ -1633,6 +1635,7 @@
         rustc_driver,
         rustc_dummy,
         rustc_dump_def_parents,
+        rustc_dump_estimated_size,
         rustc_dump_item_bounds,
         rustc_dump_predicates,
         rustc_dump_user_args,
@@ -243,6 +243,20 @@ fn assert_panic(
                     StackPopCleanup::Goto { ret: None, unwind },
                 )?;
             }
+            NullPointerDereference => {
+                // Forward to `panic_null_pointer_dereference` lang item.
+                let panic_null_pointer_dereference =
+                    this.tcx.lang_items().panic_null_pointer_dereference_fn().unwrap();
+                let panic_null_pointer_dereference =
+                    ty::Instance::mono(this.tcx.tcx, panic_null_pointer_dereference);
+                this.call_function(
+                    panic_null_pointer_dereference,
+                    Abi::Rust,
+                    &[],
+                    None,
+                    StackPopCleanup::Goto { ret: None, unwind },
+                )?;
+            }
 
             _ => {
                 // Call the lang item associated with this message.
@@ -0,0 +1,26 @@
+//@ test-mir-pass: BoundsCheckElimination
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+#![crate_type = "lib"]
+
+// EMIT_MIR bounds_check_elimination.checked_array.BoundsCheckElimination.diff
+pub fn checked_array(a: &[u32; 4], i: usize) -> u32 {
+    // CHECK-LABEL: fn checked_array(
+    // CHECK: switchInt
+    // CHECK-NOT: assert(
+    if i < 4 { a[i] } else { 0 }
+}
+
+// EMIT_MIR bounds_check_elimination.repeated_index.BoundsCheckElimination.diff
+pub fn repeated_index(s: &[u32], i: usize) -> u32 {
+    // CHECK-LABEL: fn repeated_index(
+    // CHECK: assert(move {{_.*}}, "index out of bounds
+    // CHECK-NOT: assert(
+    s[i] ^ s[i]
+}
+
+// EMIT_MIR bounds_check_elimination.out_of_bounds.BoundsCheckElimination.diff
+pub fn out_of_bounds(a: &[u32; 4], i: usize) -> u32 {
+    // CHECK-LABEL: fn out_of_bounds(
+    // CHECK: assert(move {{_.*}}, "index out of bounds
+    if i < 4 { 0 } else { a[i] }
+}
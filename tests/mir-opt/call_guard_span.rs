@@ -0,0 +1,22 @@
+//@ test-mir-pass: AddCallGuards
+//@ needs-unwind
+//@ compile-flags: -Zmir-include-spans=on
+// ignore-tidy-linelength
+// Check that the block breaking a critical call edge gets the position right after the call,
+// while the call keeps its own span.
+
+#![crate_type = "lib"]
+
+#[inline(never)]
+fn answer() -> u32 {
+    42
+}
+
+// EMIT_MIR call_guard_span.guarded.AddCallGuards.diff
+pub fn guarded(b: bool, _s: String) -> u32 {
+    // CHECK-LABEL: fn guarded(
+    // CHECK: = answer() -> [return: [[guard:bb[0-9]+]], unwind: {{bb[0-9]+}}]; // scope 0 at {{.*}}call_guard_span.rs:[[line:[0-9]+]]:12: [[line]]:20
+    // CHECK: [[guard]]: {
+    // CHECK-NEXT: goto -> {{bb[0-9]+}}; // scope 0 at {{.*}}call_guard_span.rs:[[line]]:20: [[line]]:20
+    if b { answer() } else { 0 }
+}
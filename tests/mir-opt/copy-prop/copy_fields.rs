@@ -0,0 +1,39 @@
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+//@ test-mir-pass: CopyProp
+//@ compile-flags: -Zmir-opt-level=3 -Zinline-mir=no
+
+// Locals that copy a field of an argument are replaced by the field, unless the argument is moved.
+
+#![crate_type = "lib"]
+
+pub struct Pair {
+    a: (u32, u32),
+    b: u32,
+}
+
+#[inline(never)]
+fn opaque(_: u32) {}
+
+// EMIT_MIR copy_fields.nested.CopyProp.diff
+pub fn nested(p: Pair) -> u32 {
+    // CHECK-LABEL: fn nested(
+    // CHECK: debug y => ((_1.0: (u32, u32)).1: u32);
+    // CHECK: bb0: {
+    // CHECK-NEXT: = opaque(copy ((_1.0: (u32, u32)).1: u32))
+    // CHECK: _0 = Add(copy ((_1.0: (u32, u32)).0: u32), copy (_1.1: u32));
+    let x = p.a;
+    let y = x.1;
+    opaque(y);
+    x.0 + p.b
+}
+
+// EMIT_MIR copy_fields.moved.CopyProp.diff
+pub fn moved(p: Pair) -> Pair {
+    // CHECK-LABEL: fn moved(
+    // CHECK: [[x:_.*]] = copy (_1.1: u32);
+    // CHECK: = opaque(copy [[x]])
+    // CHECK: _0 = move _1;
+    let x = p.b;
+    opaque(x);
+    p
+}
@@ -0,0 +1,12 @@
+//@ test-mir-pass: GVN
+//@ compile-flags: -Coverflow-checks=off
+#![crate_type = "lib"]
+
+// EMIT_MIR gvn_commutative.commutative.GVN.diff
+pub fn commutative(x: u64, y: u64) -> (u64, u64) {
+    // CHECK-LABEL: fn commutative(
+    // CHECK: [[sum:_.*]] = Add(copy _1, copy _2);
+    // CHECK: _6 = copy [[sum]];
+    // CHECK: _0 = (copy [[sum]], copy [[sum]]);
+    (x + y, y + x)
+}
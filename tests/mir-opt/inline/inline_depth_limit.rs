@@ -0,0 +1,28 @@
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+//@ compile-flags: -Zinline-mir-depth-limit=1
+// Check that `-Zinline-mir-depth-limit` bounds how deep a chain of inlined calls can get.
+
+// EMIT_MIR inline_depth_limit.caller.Inline.diff
+pub fn caller() -> i32 {
+    // CHECK-LABEL: fn caller(
+    // CHECK: (inlined a)
+    // CHECK: (inlined b)
+    // CHECK-NOT: (inlined c)
+    // CHECK: = c()
+    a()
+}
+
+#[inline]
+fn a() -> i32 {
+    b() + 1
+}
+
+#[inline]
+fn b() -> i32 {
+    c() + 1
+}
+
+#[inline]
+fn c() -> i32 {
+    7
+}
@@ -0,0 +1,19 @@
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+// Check that the MIR inliner does not inline callees marked `#[inline(never)]`, even when they
+// are trivial enough to be inlined otherwise.
+
+// EMIT_MIR inline_never.caller.Inline.after.mir
+pub fn caller() {
+    // CHECK-LABEL: fn caller(
+    // CHECK-NOT: (inlined never_inlined)
+    // CHECK: (inlined inlined)
+    // CHECK: = never_inlined()
+    never_inlined();
+    inlined();
+}
+
+#[inline(never)]
+pub fn never_inlined() {}
+
+#[inline]
+pub fn inlined() {}
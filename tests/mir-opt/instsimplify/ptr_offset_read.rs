@@ -0,0 +1,63 @@
+//@ test-mir-pass: InstSimplify-after-simplifycfg
+#![crate_type = "lib"]
+#![feature(custom_mir, core_intrinsics)]
+
+use std::intrinsics::mir::*;
+
+// EMIT_MIR ptr_offset_read.constant_offset.InstSimplify-after-simplifycfg.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+pub fn constant_offset(a: [u32; 4]) -> u32 {
+    // CHECK-LABEL: fn constant_offset(
+    // CHECK: _0 = copy _1[2 of 4];
+    mir! {
+        let p: *const [u32; 4];
+        let q: *const u32;
+        let r: *const u32;
+        {
+            p = &raw const a;
+            q = p as *const u32;
+            r = Offset(q, 2_isize);
+            RET = *r;
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR ptr_offset_read.out_of_bounds.InstSimplify-after-simplifycfg.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+pub fn out_of_bounds(a: [u32; 4]) -> u32 {
+    // CHECK-LABEL: fn out_of_bounds(
+    // CHECK: _0 = copy (*_4);
+    mir! {
+        let p: *const [u32; 4];
+        let q: *const u32;
+        let r: *const u32;
+        {
+            p = &raw const a;
+            q = p as *const u32;
+            r = Offset(q, 4_isize);
+            RET = *r;
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR ptr_offset_read.reassigned.InstSimplify-after-simplifycfg.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+pub fn reassigned(a: [u32; 4], b: *const u32) -> u32 {
+    // CHECK-LABEL: fn reassigned(
+    // CHECK: _0 = copy (*_5);
+    mir! {
+        let p: *const [u32; 4];
+        let q: *const u32;
+        let r: *const u32;
+        {
+            p = &raw const a;
+            q = p as *const u32;
+            q = b;
+            r = Offset(q, 1_isize);
+            RET = *r;
+            Return()
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//@ test-mir-pass: InstSimplify-after-simplifycfg
+
+#![crate_type = "lib"]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{saturating_add, saturating_sub};
+
+// EMIT_MIR saturating_identity.identities.InstSimplify-after-simplifycfg.diff
+pub fn identities(x: u32, y: i64) -> (u32, u32, i64) {
+    // CHECK-LABEL: fn identities(
+    // CHECK-NOT: saturating_add
+    // CHECK-NOT: saturating_sub
+    // CHECK: [[x1:_.*]] = copy _1;
+    // CHECK: {{_.*}} = move [[x1]];
+    // CHECK: [[x2:_.*]] = copy _1;
+    // CHECK: {{_.*}} = move [[x2]];
+    // CHECK: [[y:_.*]] = copy _2;
+    // CHECK: {{_.*}} = move [[y]];
+    (saturating_add(x, 0), saturating_add(0, x), saturating_sub(y, 0))
+}
+
+// EMIT_MIR saturating_identity.not_identities.InstSimplify-after-simplifycfg.diff
+pub fn not_identities(x: u32) -> (u32, u32) {
+    // CHECK-LABEL: fn not_identities(
+    // CHECK: saturating_add::<u32>(move {{_.*}}, const 1_u32)
+    // CHECK: saturating_sub::<u32>(const 0_u32, move {{_.*}})
+    (saturating_add(x, 1), saturating_sub(0, x))
+}
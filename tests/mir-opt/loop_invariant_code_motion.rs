@@ -0,0 +1,107 @@
+//@ test-mir-pass: LoopInvariantCodeMotion
+
+#![feature(custom_mir, core_intrinsics)]
+use std::intrinsics::mir::*;
+
+// EMIT_MIR loop_invariant_code_motion.invariant_mul.LoopInvariantCodeMotion.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn invariant_mul(a: u32, b: u32, n: u32) -> u32 {
+    // CHECK-LABEL: fn invariant_mul(
+    // CHECK: bb0: {
+    // CHECK: [[m:_.*]] = Mul(copy _1, copy _2);
+    // CHECK-NEXT: goto -> bb1;
+    // CHECK: bb2: {
+    // CHECK-NOT: Mul
+    // CHECK: _0 = Add(copy _0, copy [[m]]);
+    mir! {
+        let i: u32;
+        let m: u32;
+        let c: bool;
+        {
+            i = 0;
+            RET = 0;
+            Goto(bb1)
+        }
+        bb1 = {
+            c = i < n;
+            match c { false => bb3, _ => bb2 }
+        }
+        bb2 = {
+            m = a * b;
+            RET = RET + m;
+            i = i + 1;
+            Goto(bb1)
+        }
+        bb3 = {
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR loop_invariant_code_motion.storage_markers.LoopInvariantCodeMotion.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn storage_markers(a: u32, n: u32) -> u32 {
+    // CHECK-LABEL: fn storage_markers(
+    // CHECK-NOT: StorageLive
+    // CHECK: bb0: {
+    // CHECK: [[m:_.*]] = Add(copy _1, const 7_u32);
+    // CHECK-NEXT: goto -> bb1;
+    // CHECK-NOT: StorageDead
+    mir! {
+        let i: u32;
+        let m: u32;
+        let c: bool;
+        {
+            i = 0;
+            RET = 0;
+            Goto(bb1)
+        }
+        bb1 = {
+            c = i < n;
+            match c { false => bb3, _ => bb2 }
+        }
+        bb2 = {
+            StorageLive(m);
+            m = a + 7;
+            RET = RET + m;
+            StorageDead(m);
+            i = i + 1;
+            Goto(bb1)
+        }
+        bb3 = {
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR loop_invariant_code_motion.division.LoopInvariantCodeMotion.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn division(a: u32, b: u32, n: u32) -> u32 {
+    // CHECK-LABEL: fn division(
+    // CHECK: bb2: {
+    // CHECK-NEXT: _5 = Div(copy _1, copy _2);
+    mir! {
+        let i: u32;
+        let m: u32;
+        let c: bool;
+        {
+            i = 0;
+            RET = 0;
+            Goto(bb1)
+        }
+        bb1 = {
+            c = i < n;
+            match c { false => bb3, _ => bb2 }
+        }
+        bb2 = {
+            // The divisor may be zero when the loop does not execute.
+            m = a / b;
+            RET = RET + m;
+            i = i + 1;
+            Goto(bb1)
+        }
+        bb3 = {
+            Return()
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//@ test-mir-pass: LoopUnrolling
+//@ compile-flags: -Coverflow-checks=off
+#![crate_type = "lib"]
+
+// EMIT_MIR loop_unroll.hash.LoopUnrolling.diff
+pub fn hash(x: u32) -> u32 {
+    // CHECK-LABEL: fn hash(
+    // CHECK-NOT: switchInt
+    // CHECK: _3 = const 1_u32;
+    // CHECK: _3 = const 2_u32;
+    // CHECK: _3 = const 3_u32;
+    // CHECK-NOT: switchInt
+    let mut acc = x;
+    let mut i = 0;
+    while i < 3 {
+        acc = acc * 31 + i;
+        i += 1;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll.borrowed.LoopUnrolling.diff
+pub fn borrowed(x: u32) -> u32 {
+    // CHECK-LABEL: fn borrowed(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: = &_3;
+    // CHECK: _3 = Add(copy _3, const 1_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = x;
+    let mut i = 0;
+    while i < 3 {
+        let r = &i;
+        acc = acc * 31 + *r;
+        i += 1;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll.step.LoopUnrolling.diff
+pub fn step(x: u32) -> u32 {
+    // CHECK-LABEL: fn step(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: _3 = Add(copy _3, const 2_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = x;
+    let mut i = 0;
+    while i < 6 {
+        acc = acc * 31 + i;
+        i += 2;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll.early_exit.LoopUnrolling.diff
+pub fn early_exit(x: u32) -> u32 {
+    // CHECK-LABEL: fn early_exit(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: _3 = Add(copy _3, const 1_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = x;
+    let mut i = 0;
+    while i < 3 {
+        if acc > 100 {
+            break;
+        }
+        acc = acc * 31 + i;
+        i += 1;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll.nested.LoopUnrolling.diff
+pub fn nested(x: u32) -> u32 {
+    // CHECK-LABEL: fn nested(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: goto -> bb3;
+    // CHECK: _3 = Add(copy _3, const 1_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = 0;
+    let mut i: u32 = 0;
+    while i < 3 {
+        let mut j = 0;
+        while j < x {
+            acc = acc * 31 + j;
+            j += 1;
+        }
+        i += 1;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll.over_threshold.LoopUnrolling.diff
+pub fn over_threshold(x: u32) -> u32 {
+    // CHECK-LABEL: fn over_threshold(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: _3 = Add(copy _3, const 1_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = x;
+    let mut i = 0;
+    while i < 9 {
+        acc = acc * 31 + i;
+        i += 1;
+    }
+    acc
+}
@@ -0,0 +1,35 @@
+//@ test-mir-pass: LoopUnrolling
+//@ compile-flags: -Coverflow-checks=off -Zunroll-loops-threshold=2
+#![crate_type = "lib"]
+
+// EMIT_MIR loop_unroll_threshold.two.LoopUnrolling.diff
+pub fn two(x: u32) -> u32 {
+    // CHECK-LABEL: fn two(
+    // CHECK-NOT: switchInt
+    // CHECK: _3 = const 1_u32;
+    // CHECK: _3 = const 2_u32;
+    // CHECK-NOT: switchInt
+    let mut acc = x;
+    let mut i = 0;
+    while i < 2 {
+        acc = acc * 31 + i;
+        i += 1;
+    }
+    acc
+}
+
+// EMIT_MIR loop_unroll_threshold.three.LoopUnrolling.diff
+pub fn three(x: u32) -> u32 {
+    // CHECK-LABEL: fn three(
+    // CHECK: bb1: {
+    // CHECK: switchInt(
+    // CHECK: _3 = Add(copy _3, const 1_u32);
+    // CHECK: goto -> bb1;
+    let mut acc = x;
+    let mut i = 0;
+    while i < 3 {
+        acc = acc * 31 + i;
+        i += 1;
+    }
+    acc
+}
@@ -0,0 +1,108 @@
+//@ test-mir-pass: SimplifyConstCondition-after-const-prop
+//@ ignore-windows (`cfg!(windows)` selects the other arm of `on_windows`)
+// Check that a `switchInt` on a local that was assigned a constant in the same block is replaced
+// with a `goto`.
+
+#![feature(custom_mir, core_intrinsics)]
+use std::intrinsics::mir::*;
+
+// EMIT_MIR simplify_known_discriminant.known.SimplifyConstCondition-after-const-prop.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn known() -> i32 {
+    // CHECK-LABEL: fn known(
+    // CHECK: bb0: {
+    // CHECK-NOT: switchInt
+    // CHECK: goto -> bb2;
+    mir! {
+        let x: i32;
+        {
+            x = 2;
+            match x { 1 => bb1, 2 => bb2, _ => bb3 }
+        }
+        bb1 = {
+            RET = 10;
+            Return()
+        }
+        bb2 = {
+            RET = 20;
+            Return()
+        }
+        bb3 = {
+            RET = 30;
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR simplify_known_discriminant.copied.SimplifyConstCondition-after-const-prop.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn copied() -> i32 {
+    // CHECK-LABEL: fn copied(
+    // CHECK: bb0: {
+    // CHECK-NOT: switchInt
+    // CHECK: goto -> bb1;
+    mir! {
+        let x: i32;
+        let y: i32;
+        {
+            x = 1;
+            y = Move(x);
+            match y { 1 => bb1, 2 => bb2, _ => bb3 }
+        }
+        bb1 = {
+            RET = 10;
+            Return()
+        }
+        bb2 = {
+            RET = 20;
+            Return()
+        }
+        bb3 = {
+            RET = 30;
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR simplify_known_discriminant.modified.SimplifyConstCondition-after-const-prop.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn modified() -> i32 {
+    // CHECK-LABEL: fn modified(
+    // CHECK: bb0: {
+    // CHECK: switchInt(copy _1) -> [1: bb1, 2: bb2, otherwise: bb3];
+    mir! {
+        let x: i32;
+        let r: &mut i32;
+        {
+            x = 1;
+            r = &mut x;
+            *r = 2;
+            match x { 1 => bb1, 2 => bb2, _ => bb3 }
+        }
+        bb1 = {
+            RET = 10;
+            Return()
+        }
+        bb2 = {
+            RET = 20;
+            Return()
+        }
+        bb3 = {
+            RET = 30;
+            Return()
+        }
+    }
+}
+
+// EMIT_MIR simplify_known_discriminant.on_windows.SimplifyConstCondition-after-const-prop.diff
+pub fn on_windows() -> i32 {
+    // CHECK-LABEL: fn on_windows(
+    // CHECK: bb0: {
+    // CHECK: _1 = const false;
+    // CHECK-NOT: switchInt
+    // CHECK: goto -> bb2;
+    // The other arm is left unreachable, for the next `SimplifyCfg` to remove.
+    // CHECK: bb2: {
+    // CHECK-NEXT: _0 = const 2_i32;
+    if cfg!(windows) { 1 } else { 2 }
+}
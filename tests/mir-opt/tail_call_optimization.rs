@@ -0,0 +1,35 @@
+//@ test-mir-pass: TailCallOptimization
+//@ compile-flags: -Zmir-tail-call-opt
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+#![crate_type = "lib"]
+
+// EMIT_MIR tail_call_optimization.factorial.TailCallOptimization.diff
+pub fn factorial(n: u64, acc: u64) -> u64 {
+    // CHECK-LABEL: fn factorial(
+    // CHECK-NOT: factorial(
+    // CHECK: bb0: {
+    // CHECK-NEXT: goto -> [[entry:bb.*]];
+    // CHECK: [[n:_.*]] = move {{_.*}};
+    // CHECK: _1 = move [[n]];
+    // CHECK: goto -> [[entry]];
+    // CHECK: [[entry]]: {
+    // CHECK-NEXT: StorageLive(_3);
+    if n == 0 { acc } else { factorial(n.wrapping_sub(1), acc.wrapping_mul(n)) }
+}
+
+// EMIT_MIR tail_call_optimization.not_tail.TailCallOptimization.diff
+pub fn not_tail(n: u64) -> u64 {
+    // CHECK-LABEL: fn not_tail(
+    // CHECK: not_tail(
+    // CHECK-NOT: _1 = move
+    if n == 0 { 0 } else { not_tail(n.wrapping_sub(1)).wrapping_add(1) }
+}
+
+// EMIT_MIR tail_call_optimization.borrowed.TailCallOptimization.diff
+pub fn borrowed(n: u64) -> u64 {
+    // CHECK-LABEL: fn borrowed(
+    // CHECK: borrowed(
+    // CHECK-NOT: _1 = move
+    let r = &n;
+    if *r == 0 { 0 } else { borrowed(r.wrapping_sub(1)) }
+}
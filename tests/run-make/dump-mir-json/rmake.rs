@@ -0,0 +1,50 @@
+// `-Z dump-mir-json` writes a JSON document next to each `.mir` file created by `-Z dump-mir`.
+// This test checks that the document parses, and that it describes the same body as the
+// textual dump.
+
+use run_make_support::serde_json::{self, Value};
+use run_make_support::{rfs, rustc};
+
+fn main() {
+    rustc()
+        .crate_type("lib")
+        .input("foo.rs")
+        .arg("-Zdump-mir=add_one & built")
+        .arg("-Zdump-mir-dir=dump")
+        .arg("-Zdump-mir-json")
+        .arg("-Zdump-mir-exclude-pass-number")
+        .run();
+
+    let mir = rfs::read_to_string("dump/foo.add_one.built.after.mir");
+    let json = rfs::read_to_string("dump/foo.add_one.built.after.json");
+    let json: Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["def_path"], "add_one");
+    assert_eq!(json["pass_name"], "built");
+    assert_eq!(json["disambiguator"], "after");
+    assert_eq!(json["arg_count"], 1);
+    assert_eq!(json["locals"][1]["ty"], "u32");
+
+    // Every block, statement and terminator of the textual dump is present in the document,
+    // and the successors are consistent with the blocks that exist.
+    let blocks = json["basic_blocks"].as_array().unwrap();
+    assert!(blocks.len() > 1);
+    for block in blocks {
+        let name = block["block"].as_str().unwrap();
+        assert!(mir.contains(&format!("{name}: {{")), "missing {name}");
+        for statement in block["statements"].as_array().unwrap() {
+            let text = statement["text"].as_str().unwrap();
+            assert!(mir.contains(&format!("{text};")), "missing `{text}`");
+        }
+        let terminator = &block["terminator"];
+        assert!(mir.contains(terminator["text"].as_str().unwrap()));
+        for successor in terminator["successors"].as_array().unwrap() {
+            assert!(blocks.iter().any(|block| block["block"] == *successor));
+        }
+    }
+
+    // Serializing the parsed document again yields the same value.
+    let round_trip: Value = serde_json::from_str(&serde_json::to_string(&json).unwrap()).unwrap();
+    assert_eq!(json, round_trip);
+}
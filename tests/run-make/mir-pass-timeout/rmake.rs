@@ -0,0 +1,26 @@
+// Check that `-Zmir-pass-timeout` warns about the MIR passes that reach the timeout, and only
+// about those. Each pass takes a non-deterministic amount of time, so this is not a ui test.
+
+use run_make_support::rustc;
+
+fn main() {
+    // Every pass takes at least 0s.
+    rustc()
+        .input("lib.rs")
+        .crate_type("lib")
+        .emit("mir")
+        .arg("-Zmir-pass-timeout=0")
+        .run()
+        .assert_stderr_contains("warning: MIR pass `")
+        .assert_stderr_contains("s on this body")
+        .assert_stderr_contains("the limit set by `-Zmir-pass-timeout` is 0s");
+
+    // No pass takes an hour on this crate.
+    rustc()
+        .input("lib.rs")
+        .crate_type("lib")
+        .emit("mir")
+        .arg("-Zmir-pass-timeout=3600")
+        .run()
+        .assert_stderr_not_contains("warning");
+}
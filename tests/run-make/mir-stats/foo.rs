@@ -0,0 +1,7 @@
+pub fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+pub fn pick(b: bool, x: u32, y: u32) -> u32 {
+    if b { x } else { y }
+}
@@ -0,0 +1,23 @@
+// `-Z mir-stats` prints, for each MIR phase, how many statements and terminators of each kind
+// the bodies of the crate contain. With `-Z mir-stats=verbose`, the counts of each body follow.
+
+use run_make_support::rustc;
+
+fn main() {
+    let output = rustc().crate_type("lib").input("foo.rs").arg("-Zmir-stats").run();
+    let stderr = output.stderr_utf8();
+    assert!(stderr.contains("mir-stats after phase `runtime-optimized`:"), "{stderr}");
+    assert!(stderr.contains("statements: "), "{stderr}");
+    assert!(stderr.contains("terminators: "), "{stderr}");
+    // Both functions end in a `return`, and `pick` needs a `switchInt`.
+    assert!(stderr.contains("Return"), "{stderr}");
+    assert!(stderr.contains("SwitchInt"), "{stderr}");
+    assert!(!stderr.contains("    add_one:"), "{stderr}");
+
+    let output = rustc().crate_type("lib").input("foo.rs").arg("-Zmir-stats=verbose").run();
+    let stderr = output.stderr_utf8();
+    assert!(stderr.contains("    add_one:"), "{stderr}");
+    assert!(stderr.contains("    pick:"), "{stderr}");
+
+    rustc().crate_type("lib").input("foo.rs").arg("-Zmir-stats=all").run_fail();
+}
@@ -0,0 +1,72 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/validate-mir-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that checks MIR bodies with
+//! `rustc_mir_transform::validate_mir`. The optimized MIR of each function of
+//! the input is expected to be valid, and to become invalid when the type of
+//! its return place is changed.
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_mir_transform;
+
+use rustc_driver::Compilation;
+use rustc_hir::def::DefKind;
+use rustc_interface::interface::Compiler;
+use rustc_interface::Queries;
+use rustc_middle::mir::{Location, RETURN_PLACE};
+use rustc_mir_transform::validate_mir;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        rustc_driver::RunCompiler::new(&rustc_args, &mut CompilerCalls).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        compiler.sess.dcx().abort_if_errors();
+        queries.global_ctxt().unwrap().enter(|tcx| {
+            let mut checked = 0;
+            for id in tcx.hir_crate_items(()).free_items() {
+                if !matches!(tcx.def_kind(id.owner_id), DefKind::Fn) {
+                    continue;
+                }
+
+                let body = tcx.optimized_mir(id.owner_id);
+                let errors = validate_mir(tcx, body);
+                assert!(errors.is_empty(), "{errors:?}");
+
+                // `_0 = const 1_u32` no longer type checks.
+                let mut broken = body.clone();
+                broken.local_decls[RETURN_PLACE].ty = tcx.types.bool;
+                let errors = validate_mir(tcx, &broken);
+                let error = errors
+                    .iter()
+                    .find(|error| error.location == Location::START)
+                    .unwrap_or_else(|| panic!("{errors:?}"));
+                assert!(error.message.contains("with incompatible types"), "{error:?}");
+                assert_eq!(error.span, body.source_info(Location::START).span);
+                checked += 1;
+            }
+            assert_eq!(checked, 1);
+        });
+
+        Compilation::Stop
+    }
+}
@@ -0,0 +1,21 @@
+//@ run-pass
+// Tests that array repeat expressions whose length is a const parameter are promoted,
+// both in generic and in monomorphic code.
+fn repeat<const N: usize>() -> &'static [u8; N] {
+    &[7; N]
+}
+
+const fn repeat_const<const N: usize>() -> &'static [u8; N] {
+    &[7; N]
+}
+
+fn repeat_concrete() -> &'static [u8; 4] {
+    &[7; 4]
+}
+
+fn main() {
+    assert_eq!(repeat::<3>(), &[7, 7, 7]);
+    assert_eq!(repeat::<0>(), &[]);
+    assert_eq!(repeat_const::<2>(), &[7, 7]);
+    assert_eq!(repeat_concrete(), &[7; 4]);
+}
@@ -0,0 +1,9 @@
+// Check that the error for a borrow of interior mutable data in the final value of a constant
+// names the type that has interior mutability.
+
+use std::cell::Cell;
+
+const CELLS: &[Cell<u8>; 4] = &[Cell::new(0), Cell::new(1), Cell::new(2), Cell::new(3)];
+//~^ ERROR constants cannot refer to interior mutable data
+
+fn main() {}
@@ -0,0 +1,32 @@
+// Check the size estimated from the optimized MIR of a few functions. The bonuses the inliner
+// gives, e.g. for an `unreachable` terminator, must not make a function look smaller.
+
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+#[rustc_dump_estimated_size]
+pub fn empty() {} //~ ERROR estimated size: 0
+
+#[rustc_dump_estimated_size]
+pub fn xor(a: u32, b: u32) -> u32 {
+    //~^ ERROR estimated size: 1
+    a ^ b
+}
+
+#[rustc_dump_estimated_size]
+pub fn unwrap_or_zero(x: Option<u32>) -> u32 {
+    //~^ ERROR estimated size: 5
+    match x {
+        Some(v) => v,
+        None => 0,
+    }
+}
+
+#[rustc_dump_estimated_size]
+pub fn call(x: u32) -> u32 {
+    //~^ ERROR estimated size: 5
+    std::hint::black_box(x)
+}
+
+#[rustc_dump_estimated_size]
+pub struct NotAFunction; //~ ERROR `#[rustc_dump_estimated_size]` can only be applied to functions
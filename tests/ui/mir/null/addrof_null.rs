@@ -0,0 +1,18 @@
+// Taking the address of a place behind a null pointer does not read it, so it is not checked.
+
+//@ needs-sanitizer-support
+//@ needs-sanitizer-address
+//@ ignore-cross-compile
+//@ run-pass
+//@ compile-flags: -Z sanitizer=address
+
+struct Field {
+    a: u32,
+}
+
+fn main() {
+    let ptr: *const Field = std::ptr::null();
+    unsafe {
+        let _ptr = &raw const (*ptr).a;
+    }
+}
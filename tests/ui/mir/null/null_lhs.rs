@@ -0,0 +1,13 @@
+//@ needs-sanitizer-support
+//@ needs-sanitizer-address
+//@ ignore-cross-compile
+//@ run-fail
+//@ compile-flags: -Z sanitizer=address
+//@ error-pattern: null pointer dereference occurred
+
+fn main() {
+    let ptr: *mut u32 = std::ptr::null_mut();
+    unsafe {
+        *(ptr) = 42;
+    }
+}
@@ -0,0 +1,13 @@
+//@ needs-sanitizer-support
+//@ needs-sanitizer-address
+//@ ignore-cross-compile
+//@ run-fail
+//@ compile-flags: -Z sanitizer=address
+//@ error-pattern: null pointer dereference occurred
+
+fn main() {
+    let ptr: *const u32 = std::ptr::null();
+    unsafe {
+        let _v = *ptr;
+    }
+}
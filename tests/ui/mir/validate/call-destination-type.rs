@@ -0,0 +1,25 @@
+// Check that validation rejects a call whose destination does not have the return type.
+//
+//@ failure-status: 101
+//@ dont-check-compiler-stderr
+//@ error-pattern: encountered call returning u32 into destination of type bool
+#![feature(custom_mir, core_intrinsics)]
+extern crate core;
+use core::intrinsics::mir::*;
+
+fn f() -> u32 {
+    0
+}
+
+#[custom_mir(dialect = "built")]
+pub fn main() {
+    mir! {
+        let x: bool;
+        {
+            Call(x = f(), ReturnTo(block), UnwindContinue())
+        }
+        block = {
+            Return()
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Check that an ICE in the checks after a MIR pass notes the pass and the body it ran on.
+//
+//@ compile-flags: -Zvalidate-mir
+//@ failure-status: 101
+//@ dont-check-compiler-stderr
+//@ error-pattern: encountered call returning u32 into destination of type bool
+//@ error-pattern: the compiler panicked while checking the MIR of `main` after pass `CheckPackedRef`
+#![feature(custom_mir, core_intrinsics)]
+extern crate core;
+use core::intrinsics::mir::*;
+
+fn f() -> u32 {
+    0
+}
+
+#[custom_mir(dialect = "built")]
+pub fn main() {
+    mir! {
+        let x: bool;
+        {
+            Call(x = f(), ReturnTo(block), UnwindContinue())
+        }
+        block = {
+            Return()
+        }
+    }
+}
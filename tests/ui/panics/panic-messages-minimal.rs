@@ -0,0 +1,9 @@
+//@ run-fail
+//@ check-run-results
+//@ compile-flags: -O -C panic-messages=minimal -Z unstable-options
+//@ exec-env:RUST_BACKTRACE=0
+
+fn main() {
+    let a = [1, 2, 3];
+    std::hint::black_box(a[std::hint::black_box(5)]);
+}